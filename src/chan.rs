@@ -133,12 +133,38 @@ pub const fn u16_to_u32(fraction: u16) -> u32 {
 /// Upscale `u12` fraction to [`u32`] fraction.
 #[inline(always)]
 pub const fn u12_to_u32(fraction: u16) -> u32 {
-    let fraction = fraction as u32;
-    let upper = fraction << 4;
-    let middle = upper >> 12;
-    let lower = upper >> 24;
+    uint_to_u32(fraction as u32, 12)
+}
+
+/// Upscale `u10` fraction to [`u32`] fraction.
+#[inline(always)]
+pub const fn u10_to_u32(fraction: u16) -> u32 {
+    let upper = ((fraction as u32) << 22) & 0xffc0_0000;
 
-    upper | middle | lower
+    upper | (upper >> 10) | (upper >> 20) | (upper >> 30)
+}
+
+/// Upscale `bits`-wide unsigned fraction to [`u32`] fraction.
+///
+/// Bits above `bits` are ignored, and the fraction is replicated to fill the
+/// lower bits (so that the maximum maps to [`u32::MAX`]).
+///
+/// # Panics
+///
+/// Panics if `bits` is not from 1 to 32.
+#[inline(always)]
+pub const fn uint_to_u32(fraction: u32, bits: u32) -> u32 {
+    assert!(bits >= 1 && bits <= 32, "bits out of range");
+
+    let mut fraction = fraction << (u32::BITS - bits);
+    let mut filled = bits;
+
+    while filled < u32::BITS {
+        fraction |= fraction >> filled;
+        filled *= 2;
+    }
+
+    fraction
 }
 
 /// Upscale [`u8`] fraction to [`u32`] fraction.
@@ -186,6 +212,48 @@ pub const fn i12_to_i32(mut fraction: i16) -> i32 {
     Signed(u12_to_u32(fraction)).reinterpret_with_offset()
 }
 
+/// Upscale `i10` fraction to [`i32`] fraction.
+#[inline(always)]
+pub const fn i10_to_i32(mut fraction: i16) -> i32 {
+    if fraction > 2_i16.pow(9) - 1 {
+        fraction = 2_i16.pow(9) - 1;
+    }
+
+    if fraction < -2_i16.pow(9) {
+        fraction = -2_i16.pow(9);
+    }
+
+    let fraction = (Unsigned(fraction).reinterpret() ^ (1 << 9)) & 0x3ff;
+
+    Signed(u10_to_u32(fraction)).reinterpret_with_offset()
+}
+
+/// Upscale `bits`-wide signed fraction to [`i32`] fraction.
+///
+/// Values outside of the range of `bits` are clamped.
+///
+/// # Panics
+///
+/// Panics if `bits` is not from 1 to 32.
+#[inline(always)]
+pub const fn int_to_i32(mut fraction: i32, bits: u32) -> i32 {
+    assert!(bits >= 1 && bits <= 32, "bits out of range");
+
+    let shift = i32::BITS - bits;
+
+    if fraction > i32::MAX >> shift {
+        fraction = i32::MAX >> shift;
+    }
+
+    if fraction < i32::MIN >> shift {
+        fraction = i32::MIN >> shift;
+    }
+
+    let fraction = Unsigned(fraction << shift).reinterpret_with_offset();
+
+    Signed(uint_to_u32(fraction >> shift, bits)).reinterpret_with_offset()
+}
+
 /// Upscale [`i8`] fraction to [`i32`] fraction.
 #[inline(always)]
 pub const fn i8_to_i32(fraction: i8) -> i32 {
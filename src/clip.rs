@@ -0,0 +1,281 @@
+//! Clip detection, soft clipping, and limiting
+//!
+//! Floating-point channels can exceed full scale, but are hard-clipped when
+//! converted to integer channels.  [`Clipping`] counts the channel values that
+//! would clip, or soft-clips them with a [`Shape`] (which saturates smoothly
+//! towards full scale):
+//!
+//! ```rust
+//! use p_chan::{clip::Shape, ops::Clipping, signed::Ch32};
+//!
+//! let mut buffer = [Ch32::new(0.5), Ch32::new(1.5), Ch32::new(-3.0)];
+//!
+//! assert_eq!(Clipping(&buffer[..]).count(), 2);
+//!
+//! Clipping(&mut buffer[..]).soft_clip(Shape::Cubic);
+//!
+//! assert_eq!(Clipping(&buffer[..]).count(), 0);
+//! assert_eq!(buffer[1], Ch32::MAX);
+//! assert_eq!(buffer[2], Ch32::MIN);
+//! ```
+//!
+//! A [`Limiter`] instead reduces gain ahead of peaks, so that the output never
+//! exceeds a ceiling (at the cost of latency):
+//!
+//! ```rust
+//! use p_chan::{clip::Limiter, gain::Gain, ops::Clipping, signed::Ch32};
+//!
+//! let mut limiter = Limiter::<Ch32>::new(Gain::from_db(-1.0), 64, 4_800);
+//! let mut buffer = [Ch32::new(0.0); 256];
+//!
+//! buffer[100] = Ch32::new(2.0);
+//! limiter.process(&mut buffer);
+//!
+//! assert_eq!(Clipping(&buffer[..]).count(), 0);
+//! assert!(buffer[164].into_inner() <= Gain::from_db(-1.0).into_inner());
+//! ```
+//!
+//! [`Clipping`]: crate::ops::Clipping
+
+use core::{f64::consts::FRAC_PI_2, marker::PhantomData};
+
+use crate::{gain::Gain, math, ops::Clipping};
+
+/// Maximum lookahead of a [`Limiter`] (in samples)
+pub const MAX_LOOKAHEAD: usize = 512;
+
+/// Soft clipping shape (transfer curve)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Shape {
+    /// Hyperbolic tangent (unity slope at zero, approaching full scale)
+    Tanh,
+    /// Cubic polynomial, `1.5x - 0.5x³` (reaching full scale at full scale)
+    Cubic,
+    /// Quarter sine wave, `sin(πx / 2)` (reaching full scale at full scale)
+    Sine,
+}
+
+impl Shape {
+    /// Shape a sample.
+    const fn apply(self, x: f64) -> f64 {
+        let magnitude = x.abs();
+        let shaped = match self {
+            Self::Tanh => {
+                let t = math::exp(-2.0 * magnitude);
+
+                (1.0 - t) / (1.0 + t)
+            }
+            _ if magnitude >= 1.0 => 1.0,
+            Self::Cubic => {
+                1.5 * magnitude - 0.5 * magnitude * magnitude * magnitude
+            }
+            Self::Sine => math::sin(FRAC_PI_2 * magnitude),
+        };
+
+        shaped.copysign(x)
+    }
+}
+
+/// Lookahead brickwall limiter for one channel
+///
+/// Gain reduction ramps down over the lookahead before each peak, and
+/// recovers exponentially over the release time.
+#[derive(Clone, Debug)]
+pub struct Limiter<C> {
+    ceiling: f64,
+    lookahead: usize,
+    /// Release smoothing coefficient
+    release: f64,
+    /// Delayed input samples
+    delay: [f64; MAX_LOOKAHEAD],
+    /// Gain required by each delayed input sample (and the current one)
+    required: [f64; MAX_LOOKAHEAD + 1],
+    /// Recent held (and released) gains, averaged to smooth the attack
+    held: [f64; MAX_LOOKAHEAD],
+    /// Current released gain
+    gain: f64,
+    /// Number of samples processed
+    position: usize,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Limiter<C> {
+    /// Create a new limiter with a ceiling, a lookahead (and latency) in
+    /// samples, and a release time constant in samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lookahead is zero or more than [`MAX_LOOKAHEAD`].
+    pub const fn new(ceiling: Gain, lookahead: usize, release: usize) -> Self {
+        assert!(
+            lookahead != 0 && lookahead <= MAX_LOOKAHEAD,
+            "invalid lookahead",
+        );
+
+        let release = if release == 0 {
+            0.0
+        } else {
+            math::exp(-1.0 / release as f64)
+        };
+
+        Self {
+            ceiling: ceiling.into_inner().abs() as f64,
+            lookahead,
+            release,
+            delay: [0.0; MAX_LOOKAHEAD],
+            required: [1.0; MAX_LOOKAHEAD + 1],
+            held: [1.0; MAX_LOOKAHEAD],
+            gain: 1.0,
+            position: 0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Get the latency (in samples).
+    pub const fn latency(&self) -> usize {
+        self.lookahead
+    }
+
+    /// Get the current gain reduction.
+    pub const fn gain(&self) -> Gain {
+        Gain::new(self.reduction() as f32)
+    }
+
+    /// Reset to the initial state (silent history, and no gain reduction).
+    pub const fn reset(&mut self) {
+        self.delay = [0.0; MAX_LOOKAHEAD];
+        self.required = [1.0; MAX_LOOKAHEAD + 1];
+        self.held = [1.0; MAX_LOOKAHEAD];
+        self.gain = 1.0;
+        self.position = 0;
+    }
+
+    /// Limit one sample, returning the delayed output sample.
+    const fn push(&mut self, sample: f64) -> f64 {
+        let window = self.lookahead + 1;
+        let index = self.position % self.lookahead;
+        let delayed = self.delay[index];
+        let magnitude = sample.abs();
+
+        self.delay[index] = sample;
+        self.required[self.position % window] = if magnitude > self.ceiling {
+            self.ceiling / magnitude
+        } else {
+            1.0
+        };
+
+        // Minimum required gain over the lookahead (including the delayed
+        // sample that's output now)
+        let mut minimum = 1.0f64;
+        let mut i = 0;
+
+        while i < window {
+            minimum = minimum.min(self.required[i]);
+            i += 1;
+        }
+
+        // Instant attack, and exponential release
+        self.gain = if minimum < self.gain {
+            minimum
+        } else {
+            minimum + (self.gain - minimum) * self.release
+        };
+        self.held[index] = self.gain;
+        self.position += 1;
+
+        // Guard against rounding above the ceiling
+        (delayed * self.reduction()).clamp(-self.ceiling, self.ceiling)
+    }
+
+    /// Get the gain reduction, averaging held gains to ramp down (every held
+    /// gain in the average covers the delayed sample, so the ceiling is never
+    /// exceeded).
+    const fn reduction(&self) -> f64 {
+        let mut sum = 0.0;
+        let mut i = 0;
+
+        while i < self.lookahead {
+            sum += self.held[i];
+            i += 1;
+        }
+
+        sum / self.lookahead as f64
+    }
+}
+
+macro_rules! clip {
+    ($($ch: ident: $p: ty),* $(,)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Clipping<$ch> {
+                /// Check if the channel value is outside of full scale (and
+                /// would clip on conversion to an integer channel).
+                pub const fn is_clipped(self) -> bool {
+                    let value = self.0.into_inner();
+
+                    value > $ch::MAX.into_inner()
+                        || value < $ch::MIN.into_inner()
+                }
+
+                /// Soft clip with a shape.
+                pub const fn soft_clip(self, shape: Shape) -> $ch {
+                    $ch::new(shape.apply(self.0.into_inner() as f64) as $p)
+                }
+            }
+
+            impl Clipping<&[$ch]> {
+                /// Count the channel values outside of full scale (that would
+                /// clip on conversion to an integer channel).
+                pub const fn count(self) -> usize {
+                    let mut count = 0;
+                    let mut i = 0;
+
+                    while i < self.0.len() {
+                        if Clipping(self.0[i]).is_clipped() {
+                            count += 1;
+                        }
+
+                        i += 1;
+                    }
+
+                    count
+                }
+            }
+
+            impl Clipping<&mut [$ch]> {
+                /// Soft clip each channel value with a shape.
+                pub const fn soft_clip(self, shape: Shape) {
+                    let mut i = 0;
+
+                    while i < self.0.len() {
+                        self.0[i] = Clipping(self.0[i]).soft_clip(shape);
+                        i += 1;
+                    }
+                }
+            }
+
+            #[allow(trivial_numeric_casts)]
+            impl Limiter<$ch> {
+                /// Limit channel values in place (delayed by
+                /// [`Limiter::latency()`] samples).
+                pub const fn process(&mut self, chans: &mut [$ch]) {
+                    let mut i = 0;
+
+                    while i < chans.len() {
+                        let sample = chans[i].into_inner() as f64;
+
+                        chans[i] = $ch::new(self.push(sample) as $p);
+                        i += 1;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mod signed {
+    use super::*;
+    use crate::signed::{Ch32, Ch64};
+
+    clip!(Ch32: f32, Ch64: f64);
+}
@@ -0,0 +1,309 @@
+//! Alpha premultiplication and compositing
+//!
+//! Compositing operates on premultiplied [`Rgba`] and [`Bgra`] pixels built
+//! from unsigned channels, with Porter-Duff [`Operator`]s and separable
+//! [`Blend`] modes (as defined by the W3C Compositing and Blending spec).
+//! Integer channels are calculated in floating-point, then rounded to nearest.
+//!
+//! ```rust
+//! use p_chan::{
+//!     composite::Operator,
+//!     ops::{Composition, Premultiplication},
+//!     pixel::Rgba,
+//!     unsigned::Ch8,
+//! };
+//!
+//! let source = Rgba::new(Ch8::MAX, Ch8::MIN, Ch8::MIN, Ch8::new(51));
+//! let source = Premultiplication(source).premultiply();
+//! let destination = Rgba::<Ch8>::WHITE;
+//! let composited = Composition(source, destination).composite(Operator::SrcOver);
+//!
+//! assert_eq!(
+//!     composited,
+//!     Rgba::new(Ch8::MAX, Ch8::new(204), Ch8::new(204), Ch8::MAX),
+//! );
+//! ```
+
+use crate::{
+    math,
+    ops::{Composition, Conversion, Premultiplication},
+    pixel::{Bgra, Rgba},
+    unsigned::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64},
+};
+
+/// Porter-Duff compositing operator
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Operator {
+    /// Neither source nor destination
+    Clear,
+    /// Source only
+    Src,
+    /// Destination only
+    Dst,
+    /// Source over destination
+    SrcOver,
+    /// Destination over source
+    DstOver,
+    /// Source where destination is
+    SrcIn,
+    /// Destination where source is
+    DstIn,
+    /// Source where destination isn't
+    SrcOut,
+    /// Destination where source isn't
+    DstOut,
+    /// Source over destination, where destination is
+    SrcAtop,
+    /// Destination over source, where source is
+    DstAtop,
+    /// Source where destination isn't, and destination where source isn't
+    Xor,
+    /// Sum of source and destination
+    Plus,
+}
+
+impl Operator {
+    /// Get the source and destination factors for source and destination
+    /// alpha.
+    const fn factors(self, source: f64, destination: f64) -> (f64, f64) {
+        match self {
+            Self::Clear => (0.0, 0.0),
+            Self::Src => (1.0, 0.0),
+            Self::Dst => (0.0, 1.0),
+            Self::SrcOver => (1.0, 1.0 - source),
+            Self::DstOver => (1.0 - destination, 1.0),
+            Self::SrcIn => (destination, 0.0),
+            Self::DstIn => (0.0, source),
+            Self::SrcOut => (1.0 - destination, 0.0),
+            Self::DstOut => (0.0, 1.0 - source),
+            Self::SrcAtop => (destination, 1.0 - source),
+            Self::DstAtop => (1.0 - destination, source),
+            Self::Xor => (1.0 - destination, 1.0 - source),
+            Self::Plus => (1.0, 1.0),
+        }
+    }
+}
+
+/// Separable blend mode (composited with source over)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Blend {
+    /// Source replaces backdrop
+    Normal,
+    /// Product of source and backdrop
+    Multiply,
+    /// Inverse of product of inverted source and backdrop
+    Screen,
+    /// Multiply or screen, depending on backdrop
+    Overlay,
+    /// Minimum of source and backdrop
+    Darken,
+    /// Maximum of source and backdrop
+    Lighten,
+    /// Brighten backdrop to reflect source
+    ColorDodge,
+    /// Darken backdrop to reflect source
+    ColorBurn,
+    /// Multiply or screen, depending on source
+    HardLight,
+    /// Darken or lighten, depending on source
+    SoftLight,
+    /// Absolute difference of source and backdrop
+    Difference,
+    /// Like difference, but lower contrast
+    Exclusion,
+}
+
+impl Blend {
+    /// Blend unpremultiplied backdrop and source channel values.
+    const fn blend(self, backdrop: f64, source: f64) -> f64 {
+        match self {
+            Self::Normal => source,
+            Self::Multiply => backdrop * source,
+            Self::Screen => backdrop + source - backdrop * source,
+            Self::Overlay => Self::HardLight.blend(source, backdrop),
+            Self::Darken => backdrop.min(source),
+            Self::Lighten => backdrop.max(source),
+            Self::ColorDodge => {
+                if backdrop == 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            Self::HardLight => {
+                if source <= 0.5 {
+                    Self::Multiply.blend(backdrop, 2.0 * source)
+                } else {
+                    Self::Screen.blend(backdrop, 2.0 * source - 1.0)
+                }
+            }
+            Self::SoftLight => {
+                if source <= 0.5 {
+                    backdrop
+                        - (1.0 - 2.0 * source) * backdrop * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop + 4.0) * backdrop
+                    } else {
+                        math::sqrt(backdrop)
+                    };
+
+                    backdrop + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            Self::Difference => (backdrop - source).abs(),
+            Self::Exclusion => backdrop + source - 2.0 * backdrop * source,
+        }
+    }
+}
+
+/// Multiply color by alpha for `[r, g, b, a]`.
+const fn premultiply([r, g, b, a]: [f64; 4]) -> [f64; 4] {
+    [r * a, g * a, b * a, a]
+}
+
+/// Divide color by alpha for `[r, g, b, a]`.
+const fn unpremultiply([r, g, b, a]: [f64; 4]) -> [f64; 4] {
+    if a == 0.0 {
+        return [0.0; 4];
+    }
+
+    [r / a, g / a, b / a, a]
+}
+
+/// Composite premultiplied `[r, g, b, a]`.
+const fn composite(
+    source: [f64; 4],
+    destination: [f64; 4],
+    operator: Operator,
+) -> [f64; 4] {
+    let (fs, fd) = operator.factors(source[3], destination[3]);
+    let mut out = [0.0; 4];
+    let mut i = 0;
+
+    while i < out.len() {
+        out[i] = source[i] * fs + destination[i] * fd;
+        i += 1;
+    }
+
+    out
+}
+
+/// Blend premultiplied `[r, g, b, a]`.
+const fn blend(source: [f64; 4], backdrop: [f64; 4], mode: Blend) -> [f64; 4] {
+    let (sa, ba) = (source[3], backdrop[3]);
+    let unsource = unpremultiply(source);
+    let unbackdrop = unpremultiply(backdrop);
+    let mut out = [0.0; 4];
+    let mut i = 0;
+
+    while i < 3 {
+        let blended = mode.blend(unbackdrop[i], unsource[i]);
+
+        out[i] = source[i] * (1.0 - ba)
+            + backdrop[i] * (1.0 - sa)
+            + sa * ba * blended;
+        i += 1;
+    }
+
+    out[3] = sa + ba * (1.0 - sa);
+    out
+}
+
+const fn widen(chan: Ch32) -> Ch64 {
+    Ch64::new(chan.into_inner() as f64)
+}
+
+const fn narrow(chan: Ch64) -> Ch32 {
+    Ch32::new(chan.into_inner() as f32)
+}
+
+macro_rules! composite {
+    ($($ch: ident: $decode: expr, $encode: expr);* $(;)?) => {
+        $(
+            composite!(@pixel Rgba, $ch, $decode, $encode);
+            composite!(@pixel Bgra, $ch, $decode, $encode);
+        )*
+    };
+    (@pixel $px: ident, $ch: ident, $decode: expr, $encode: expr) => {
+        impl Premultiplication<$px<$ch>> {
+            /// Multiply the color channels by alpha.
+            pub const fn premultiply(self) -> $px<$ch> {
+                composite!(@from $px, $encode, premultiply(
+                    composite!(@into self.0, $decode),
+                ))
+            }
+
+            /// Divide the color channels by alpha (transparent pixels become
+            /// zero).
+            pub const fn unpremultiply(self) -> $px<$ch> {
+                composite!(@from $px, $encode, unpremultiply(
+                    composite!(@into self.0, $decode),
+                ))
+            }
+        }
+
+        impl Composition<$px<$ch>> {
+            /// Composite premultiplied source and destination with a
+            /// Porter-Duff operator.
+            pub const fn composite(self, operator: Operator) -> $px<$ch> {
+                composite!(@from $px, $encode, composite(
+                    composite!(@into self.0, $decode),
+                    composite!(@into self.1, $decode),
+                    operator,
+                ))
+            }
+
+            /// Blend premultiplied source onto premultiplied destination
+            /// (backdrop).
+            pub const fn blend(self, mode: Blend) -> $px<$ch> {
+                composite!(@from $px, $encode, blend(
+                    composite!(@into self.0, $decode),
+                    composite!(@into self.1, $decode),
+                    mode,
+                ))
+            }
+        }
+    };
+    (@into $pixel: expr, $decode: expr) => {{
+        let pixel = $pixel;
+
+        [
+            ($decode)(pixel.r).into_inner(),
+            ($decode)(pixel.g).into_inner(),
+            ($decode)(pixel.b).into_inner(),
+            ($decode)(pixel.a).into_inner(),
+        ]
+    }};
+    (@from $px: ident, $encode: expr, $array: expr) => {{
+        let [r, g, b, a] = $array;
+
+        $px {
+            r: ($encode)(Ch64::new(r)),
+            g: ($encode)(Ch64::new(g)),
+            b: ($encode)(Ch64::new(b)),
+            a: ($encode)(Ch64::new(a)),
+        }
+    }};
+}
+
+composite!(
+    Ch8: Conversion::<Ch8, Ch64>::convert, Conversion::<Ch64, Ch8>::convert;
+    Ch10: Conversion::<Ch10, Ch64>::convert, Conversion::<Ch64, Ch10>::convert;
+    Ch12: Conversion::<Ch12, Ch64>::convert, Conversion::<Ch64, Ch12>::convert;
+    Ch16: Conversion::<Ch16, Ch64>::convert, Conversion::<Ch64, Ch16>::convert;
+    Ch24: Conversion::<Ch24, Ch64>::convert, Conversion::<Ch64, Ch24>::convert;
+    Ch32: widen, narrow;
+    Ch64: core::convert::identity, core::convert::identity;
+);
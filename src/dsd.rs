@@ -0,0 +1,289 @@
+//! 1-bit DSD (Direct Stream Digital) channels and PCM conversion
+//!
+//! DSD streams are stored as packed bytes of 8 samples, with the oldest sample
+//! in the most significant bit (as in DSDIFF files; reverse the bits of each
+//! byte for DSF files).  A set bit is `+1`, and a clear bit is `-1`.
+//!
+//! [`Decimator`] converts DSD streams to PCM, and [`Modulator`] converts PCM
+//! to DSD streams.  The decimation ratio is the number of DSD samples per PCM
+//! sample (for example, 64 converts DSD64 at 2.8224 MHz to 44.1 kHz).
+
+use crate::{math, ops::Conversion, signed::Ch32};
+
+/// Maximum number of FIR filter taps
+const MAX_TAPS: usize = 2_048;
+/// FIR filter taps per DSD sample of decimation ratio
+const TAPS_PER_RATIO: usize = 16;
+/// Byte pattern for DSD silence
+const SILENCE: u8 = 0x69;
+/// Modulator integrator limit (to stay stable when overloaded)
+const INTEGRATOR_LIMIT: f64 = 8.0;
+
+/// 1-bit DSD channel value (`false` is -1, and `true` is +1)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[repr(transparent)]
+pub struct Ch1(bool);
+
+impl Ch1 {
+    /// Maximum value
+    pub const MAX: Self = Self(true);
+    /// Minimum value
+    pub const MIN: Self = Self(false);
+
+    /// Create a new channel value.
+    pub const fn new(value: bool) -> Self {
+        Self(value)
+    }
+
+    /// Get the inner primitive channel value.
+    pub const fn into_inner(self) -> bool {
+        self.0
+    }
+}
+
+impl Conversion<Ch1, Ch32> {
+    /// Convert between types.
+    pub const fn convert(from: Ch1) -> Ch32 {
+        if from.0 { Ch32::MAX } else { Ch32::MIN }
+    }
+}
+
+impl Conversion<Ch32, Ch1> {
+    /// Convert between types (by sign).
+    pub const fn convert(from: Ch32) -> Ch1 {
+        Ch1(!from.into_inner().is_sign_negative())
+    }
+}
+
+impl Conversion<u8, [Ch1; 8]> {
+    /// Convert between types (unpacking most significant bit first).
+    pub const fn convert(from: u8) -> [Ch1; 8] {
+        let mut chans = [Ch1::MIN; 8];
+        let mut i = 0;
+
+        while i < chans.len() {
+            chans[i] = Ch1((from << i) & 0x80 != 0);
+            i += 1;
+        }
+
+        chans
+    }
+}
+
+impl Conversion<[Ch1; 8], u8> {
+    /// Convert between types (packing most significant bit first).
+    pub const fn convert(from: [Ch1; 8]) -> u8 {
+        let mut byte = 0;
+        let mut i = 0;
+
+        while i < from.len() {
+            byte |= (from[i].0 as u8) << (7 - i);
+            i += 1;
+        }
+
+        byte
+    }
+}
+
+/// DSD to PCM converter
+///
+/// Filters with a windowed-sinc low-pass FIR, then decimates.
+// Not `Copy`, so that the (large) filter state isn't duplicated implicitly
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug)]
+pub struct Decimator {
+    coefficients: [f32; MAX_TAPS],
+    history: [u8; MAX_TAPS / 8],
+    /// Number of bytes of history used by the filter
+    len: usize,
+    /// Index of the oldest byte in history
+    position: usize,
+    /// Bytes per PCM sample
+    ratio: usize,
+    /// Bytes received since the last PCM sample
+    pending: usize,
+}
+
+impl Decimator {
+    /// Create a new decimator for `ratio` DSD samples per PCM sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not a multiple of 8 from 8 to 128.
+    pub const fn new(ratio: usize) -> Self {
+        assert!(
+            ratio % 8 == 0 && ratio >= 8 && ratio <= 128,
+            "invalid ratio",
+        );
+
+        let taps = ratio * TAPS_PER_RATIO;
+        // Cutoff frequency (relative to DSD sample rate), below PCM Nyquist
+        let cutoff = 0.45 / ratio as f64;
+        let center = (taps - 1) as f64 / 2.0;
+        let mut coefficients = [0.0; MAX_TAPS];
+        let mut sum = 0.0;
+        let mut i = 0;
+
+        while i < taps {
+            let x = i as f64 - center;
+            let sinc = if x == 0.0 {
+                2.0 * cutoff
+            } else {
+                let x = core::f64::consts::PI * x;
+
+                math::sin(2.0 * cutoff * x) / x
+            };
+            // Blackman window
+            let phase =
+                2.0 * core::f64::consts::PI * i as f64 / (taps - 1) as f64;
+            let window =
+                0.42 - 0.5 * math::cos(phase) + 0.08 * math::cos(2.0 * phase);
+
+            coefficients[i] = (sinc * window) as f32;
+            sum += sinc * window;
+            i += 1;
+        }
+
+        // Normalize for unity gain
+        let mut i = 0;
+
+        while i < taps {
+            coefficients[i] = (coefficients[i] as f64 / sum) as f32;
+            i += 1;
+        }
+
+        Self {
+            coefficients,
+            history: [SILENCE; MAX_TAPS / 8],
+            len: taps / 8,
+            position: 0,
+            ratio: ratio / 8,
+            pending: 0,
+        }
+    }
+
+    /// Convert a DSD stream into PCM channel values.
+    ///
+    /// Returns the number of PCM channel values written.  Leftover bytes that
+    /// don't make up a whole PCM sample are kept for the next call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is too short.
+    pub fn process(&mut self, input: &[u8], output: &mut [Ch32]) -> usize {
+        let mut written = 0;
+
+        for &byte in input {
+            self.history[self.position] = byte;
+            self.position = (self.position + 1) % self.len;
+            self.pending += 1;
+
+            if self.pending == self.ratio {
+                self.pending = 0;
+                output[written] = Ch32::new(self.filter());
+                written += 1;
+            }
+        }
+
+        written
+    }
+
+    /// Apply the FIR filter to the history.
+    fn filter(&self) -> f32 {
+        let mut sum = 0.0;
+
+        for i in 0..self.len {
+            let byte = self.history[(self.position + i) % self.len];
+            let coefficients = &self.coefficients[i * 8..][..8];
+
+            for (bit, coefficient) in coefficients.iter().enumerate() {
+                if (byte << bit) & 0x80 != 0 {
+                    sum += coefficient;
+                } else {
+                    sum -= coefficient;
+                }
+            }
+        }
+
+        sum
+    }
+}
+
+/// PCM to DSD converter
+///
+/// Linearly interpolates up to the DSD rate, then applies a second-order
+/// sigma-delta modulator.  Input should stay within about ±0.7 (DSD full scale
+/// is conventionally ±0.5), louder input distorts.
+// Not `Copy`, so that the modulator state isn't duplicated implicitly
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug)]
+pub struct Modulator {
+    integrators: [f64; 2],
+    /// Previous PCM sample
+    previous: f64,
+    /// Previous DSD sample
+    output: bool,
+    /// DSD samples per PCM sample
+    ratio: usize,
+}
+
+impl Modulator {
+    /// Create a new modulator for `ratio` DSD samples per PCM sample.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ratio` is not a multiple of 8 from 8 to 128.
+    pub const fn new(ratio: usize) -> Self {
+        assert!(
+            ratio % 8 == 0 && ratio >= 8 && ratio <= 128,
+            "invalid ratio",
+        );
+
+        Self {
+            integrators: [0.0; 2],
+            previous: 0.0,
+            output: false,
+            ratio,
+        }
+    }
+
+    /// Convert PCM channel values into a DSD stream.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` isn't exactly the length of `input` multiplied by
+    /// `ratio / 8`.
+    pub fn process(&mut self, input: &[Ch32], output: &mut [u8]) {
+        assert_eq!(
+            input.len() * self.ratio / 8,
+            output.len(),
+            "output length mismatch",
+        );
+
+        let mut index = 0;
+
+        output.fill(0);
+
+        for chan in input {
+            let sample = (chan.into_inner() as f64).clamp(-1.0, 1.0);
+
+            for step in 1..=self.ratio {
+                let t = step as f64 / self.ratio as f64;
+                let x = self.previous + (sample - self.previous) * t;
+                let y = if self.output { 1.0 } else { -1.0 };
+                let [first, second] = &mut self.integrators;
+
+                *first =
+                    (*first + x - y).clamp(-INTEGRATOR_LIMIT, INTEGRATOR_LIMIT);
+                *second = (*second + *first - 2.0 * y)
+                    .clamp(-INTEGRATOR_LIMIT, INTEGRATOR_LIMIT);
+                self.output = *second >= 0.0;
+
+                output[index / 8] |= (self.output as u8) << (7 - index % 8);
+                index += 1;
+            }
+
+            self.previous = sample;
+        }
+    }
+}
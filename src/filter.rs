@@ -0,0 +1,461 @@
+//! Biquad and one-pole filters
+//!
+//! [`Coefficients`] for biquad filters are designed with the formulas from
+//! Robert Bristow-Johnson's "Audio EQ Cookbook", and applied to a buffer of
+//! channel values by a [`Biquad`].  A [`OnePole`] is a cheaper first-order
+//! low-pass or high-pass (DC blocking) filter.
+//!
+//! Filters keep their state between buffers (use one filter per channel).
+//! Integer channels are filtered as floating-point, then rounded and
+//! saturated.  Unsigned integer channels are centered on their midpoint
+//! while filtering, so that high-pass filters remove DC around it.
+//!
+//! ```rust
+//! use p_chan::{
+//!     filter::{Biquad, Coefficients, OnePole},
+//!     signed::{Ch16, Ch32},
+//! };
+//!
+//! // Remove a DC offset
+//! let mut dc_blocker = OnePole::<Ch16>::high_pass(48_000, 10.0);
+//! let mut buffer = [Ch16::new(1_000); 48_000];
+//!
+//! dc_blocker.process(&mut buffer);
+//!
+//! assert_eq!(buffer[47_999], Ch16::new(0));
+//!
+//! // Low-pass at 1 kHz
+//! let coefficients = Coefficients::low_pass(48_000, 1_000.0, 0.707);
+//! let mut low_pass = Biquad::<Ch32>::new(coefficients);
+//! let mut buffer = [Ch32::new(0.5); 4_800];
+//!
+//! low_pass.process(&mut buffer);
+//!
+//! assert!((buffer[4_799].into_inner() - 0.5).abs() < 1e-6);
+//!
+//! // -3 dB at the cutoff frequency
+//! let response = coefficients.magnitude(48_000, 1_000.0);
+//!
+//! assert!((response.to_db() + 3.0).abs() < 0.05);
+//! ```
+
+use core::{f64::consts::TAU, marker::PhantomData};
+
+use crate::{gain::Gain, math};
+
+/// Biquad filter coefficients (normalized so that a₀ is 1)
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Coefficients {
+    b: [f64; 3],
+    a: [f64; 2],
+}
+
+impl Coefficients {
+    /// Identity filter (passes input through unchanged)
+    pub const IDENTITY: Self = Self {
+        b: [1.0, 0.0, 0.0],
+        a: [0.0, 0.0],
+    };
+
+    /// Create coefficients for the transfer function
+    /// `(b₀ + b₁z⁻¹ + b₂z⁻²) / (a₀ + a₁z⁻¹ + a₂z⁻²)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a[0]` is zero.
+    pub const fn new(b: [f64; 3], a: [f64; 3]) -> Self {
+        assert!(a[0] != 0.0, "zero a0");
+
+        Self {
+            b: [b[0] / a[0], b[1] / a[0], b[2] / a[0]],
+            a: [a[1] / a[0], a[2] / a[0]],
+        }
+    }
+
+    /// Create a low-pass filter with a cutoff frequency (in Hz) and Q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn low_pass(rate: u32, frequency: f32, q: f32) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+
+        Self::new(
+            [(1.0 - cos) / 2.0, 1.0 - cos, (1.0 - cos) / 2.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Create a high-pass filter with a cutoff frequency (in Hz) and Q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn high_pass(rate: u32, frequency: f32, q: f32) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+
+        Self::new(
+            [(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Create a band-pass filter (with unity gain at the center frequency)
+    /// with a center frequency (in Hz) and Q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn band_pass(rate: u32, frequency: f32, q: f32) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+
+        Self::new([alpha, 0.0, -alpha], [1.0 + alpha, -2.0 * cos, 1.0 - alpha])
+    }
+
+    /// Create a notch (band-stop) filter with a center frequency (in Hz) and
+    /// Q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn notch(rate: u32, frequency: f32, q: f32) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+
+        Self::new(
+            [1.0, -2.0 * cos, 1.0],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Create an all-pass filter with a center frequency (in Hz) and Q.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn all_pass(rate: u32, frequency: f32, q: f32) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+
+        Self::new(
+            [1.0 - alpha, -2.0 * cos, 1.0 + alpha],
+            [1.0 + alpha, -2.0 * cos, 1.0 - alpha],
+        )
+    }
+
+    /// Create a peaking EQ filter with a center frequency (in Hz), Q, and
+    /// gain at the center frequency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn peaking(
+        rate: u32,
+        frequency: f32,
+        q: f32,
+        gain: Gain,
+    ) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let alpha = sin / (2.0 * q as f64);
+        let a = shelf_amplitude(gain);
+
+        Self::new(
+            [1.0 + alpha * a, -2.0 * cos, 1.0 - alpha * a],
+            [1.0 + alpha / a, -2.0 * cos, 1.0 - alpha / a],
+        )
+    }
+
+    /// Create a low shelf filter with a corner frequency (in Hz), Q, and gain
+    /// below the corner frequency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn low_shelf(
+        rate: u32,
+        frequency: f32,
+        q: f32,
+        gain: Gain,
+    ) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let a = shelf_amplitude(gain);
+        let beta = 2.0 * math::sqrt(a) * sin / (2.0 * q as f64);
+
+        Self::new(
+            [
+                a * ((a + 1.0) - (a - 1.0) * cos + beta),
+                2.0 * a * ((a - 1.0) - (a + 1.0) * cos),
+                a * ((a + 1.0) - (a - 1.0) * cos - beta),
+            ],
+            [
+                (a + 1.0) + (a - 1.0) * cos + beta,
+                -2.0 * ((a - 1.0) + (a + 1.0) * cos),
+                (a + 1.0) + (a - 1.0) * cos - beta,
+            ],
+        )
+    }
+
+    /// Create a high shelf filter with a corner frequency (in Hz), Q, and
+    /// gain above the corner frequency.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn high_shelf(
+        rate: u32,
+        frequency: f32,
+        q: f32,
+        gain: Gain,
+    ) -> Self {
+        let (sin, cos) = angle(rate, frequency);
+        let a = shelf_amplitude(gain);
+        let beta = 2.0 * math::sqrt(a) * sin / (2.0 * q as f64);
+
+        Self::new(
+            [
+                a * ((a + 1.0) + (a - 1.0) * cos + beta),
+                -2.0 * a * ((a - 1.0) + (a + 1.0) * cos),
+                a * ((a + 1.0) + (a - 1.0) * cos - beta),
+            ],
+            [
+                (a + 1.0) - (a - 1.0) * cos + beta,
+                2.0 * ((a - 1.0) - (a + 1.0) * cos),
+                (a + 1.0) - (a - 1.0) * cos - beta,
+            ],
+        )
+    }
+
+    /// Get the magnitude response at a frequency (in Hz).
+    pub const fn magnitude(&self, rate: u32, frequency: f32) -> Gain {
+        let w = TAU * frequency as f64 / rate as f64;
+        let (sin1, cos1) = math::sin_cos(w);
+        let (sin2, cos2) = math::sin_cos(2.0 * w);
+        let [b0, b1, b2] = self.b;
+        let [a1, a2] = self.a;
+        let (num_re, num_im) =
+            (b0 + b1 * cos1 + b2 * cos2, b1 * sin1 + b2 * sin2);
+        let (den_re, den_im) =
+            (1.0 + a1 * cos1 + a2 * cos2, a1 * sin1 + a2 * sin2);
+        let numerator = num_re * num_re + num_im * num_im;
+        let denominator = den_re * den_re + den_im * den_im;
+
+        Gain::new(math::sqrt(numerator / denominator) as f32)
+    }
+
+    /// Filter a sample (transposed direct form II).
+    pub(crate) const fn filter(&self, state: &mut [f64; 2], x: f64) -> f64 {
+        let y = self.b[0] * x + state[0];
+
+        state[0] = self.b[1] * x - self.a[0] * y + state[1];
+        state[1] = self.b[2] * x - self.a[1] * y;
+        y
+    }
+}
+
+/// Get the sine and cosine of the angular frequency.
+const fn angle(rate: u32, frequency: f32) -> (f64, f64) {
+    let frequency = frequency as f64;
+
+    assert!(
+        frequency > 0.0 && frequency < rate as f64 / 2.0,
+        "invalid frequency",
+    );
+
+    math::sin_cos(TAU * frequency / rate as f64)
+}
+
+/// Get the square root of a gain (the amplitude of a shelf or peak).
+const fn shelf_amplitude(gain: Gain) -> f64 {
+    math::sqrt(gain.into_inner().abs() as f64)
+}
+
+/// Biquad filter for one channel
+#[derive(Clone, Debug)]
+pub struct Biquad<C> {
+    coefficients: Coefficients,
+    state: [f64; 2],
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Biquad<C> {
+    /// Create a new biquad filter.
+    pub const fn new(coefficients: Coefficients) -> Self {
+        Self {
+            coefficients,
+            state: [0.0; 2],
+            _chan: PhantomData,
+        }
+    }
+
+    /// Get the coefficients.
+    pub const fn coefficients(&self) -> Coefficients {
+        self.coefficients
+    }
+
+    /// Change the coefficients (keeping the filter state).
+    pub const fn set_coefficients(&mut self, coefficients: Coefficients) {
+        self.coefficients = coefficients;
+    }
+
+    /// Reset to the initial state (silent history).
+    pub const fn reset(&mut self) {
+        self.state = [0.0; 2];
+    }
+
+    const fn push(&mut self, sample: f64) -> f64 {
+        self.coefficients.filter(&mut self.state, sample)
+    }
+}
+
+/// One-pole filter for one channel
+#[derive(Clone, Debug)]
+pub struct OnePole<C> {
+    b: [f64; 2],
+    a: f64,
+    /// Previous input
+    x: f64,
+    /// Previous output
+    y: f64,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> OnePole<C> {
+    /// Create a one-pole low-pass filter with a cutoff frequency (in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn low_pass(rate: u32, frequency: f32) -> Self {
+        let pole = pole(rate, frequency);
+
+        Self::new([1.0 - pole, 0.0], pole)
+    }
+
+    /// Create a one-pole high-pass (DC blocking) filter with a cutoff
+    /// frequency (in Hz).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the frequency isn't between zero and the Nyquist frequency.
+    pub const fn high_pass(rate: u32, frequency: f32) -> Self {
+        let pole = pole(rate, frequency);
+        // Unity gain at the Nyquist frequency
+        let gain = (1.0 + pole) / 2.0;
+
+        Self::new([gain, -gain], pole)
+    }
+
+    const fn new(b: [f64; 2], pole: f64) -> Self {
+        Self {
+            b,
+            a: pole,
+            x: 0.0,
+            y: 0.0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Reset to the initial state (silent history).
+    pub const fn reset(&mut self) {
+        self.x = 0.0;
+        self.y = 0.0;
+    }
+
+    const fn push(&mut self, sample: f64) -> f64 {
+        self.y = self.b[0] * sample + self.b[1] * self.x + self.a * self.y;
+        self.x = sample;
+        self.y
+    }
+}
+
+/// Get the pole of a one-pole filter.
+const fn pole(rate: u32, frequency: f32) -> f64 {
+    let frequency = frequency as f64;
+
+    assert!(
+        frequency > 0.0 && frequency < rate as f64 / 2.0,
+        "invalid frequency",
+    );
+
+    math::exp(-TAU * frequency / rate as f64)
+}
+
+macro_rules! filter {
+    ($($kind: ident $ch: ident: $p: ty),* $(,)?) => {
+        $(
+            filter!(@filter Biquad, $kind $ch: $p);
+            filter!(@filter OnePole, $kind $ch: $p);
+        )*
+    };
+    (@filter $filter: ident, $kind: ident $ch: ident: $p: ty) => {
+        #[allow(trivial_numeric_casts)]
+        impl $filter<$ch> {
+            /// Filter channel values in place.
+            pub const fn process(&mut self, chans: &mut [$ch]) {
+                let mut i = 0;
+
+                while i < chans.len() {
+                    let sample = filter!(@into $kind $ch, chans[i]);
+                    let sample = self.push(sample);
+
+                    chans[i] = filter!(@from $kind $ch: $p, sample);
+                    i += 1;
+                }
+            }
+        }
+    };
+    (@into uint $ch: ident, $chan: expr) => {
+        $chan.into_inner() as f64 - filter!(@offset $ch)
+    };
+    (@into $kind: ident $ch: ident, $chan: expr) => {
+        $chan.into_inner() as f64
+    };
+    (@from uint $ch: ident: $p: ty, $sample: expr) => {
+        filter!(@from int $ch: $p, $sample + filter!(@offset $ch))
+    };
+    (@from int $ch: ident: $p: ty, $sample: expr) => {{
+        let min = $ch::MIN.into_inner() as f64;
+        let max = $ch::MAX.into_inner() as f64;
+
+        // Round to nearest, and saturate
+        $ch::new(math::round($sample).clamp(min, max) as $p)
+    }};
+    (@from float $ch: ident: $p: ty, $sample: expr) => {
+        $ch::new($sample as $p)
+    };
+    // Unsigned silence is halfway, rounded up (128 for 8-bit)
+    (@offset $ch: ident) => {
+        ($ch::MAX.into_inner() as f64 + 1.0) / 2.0
+    };
+}
+
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    filter!(
+        int Ch8: i8,
+        int Ch10: i16,
+        int Ch12: i16,
+        int Ch16: i16,
+        int Ch24: i32,
+        float Ch32: f32,
+        float Ch64: f64,
+    );
+}
+
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Ch8, Ch10, Ch12, Ch16, Ch24};
+
+    filter!(
+        uint Ch8: u8,
+        uint Ch10: u16,
+        uint Ch12: u16,
+        uint Ch16: u16,
+        uint Ch24: u32,
+    );
+}
@@ -0,0 +1,455 @@
+//! Audio frames and channel layouts
+//!
+//! A [`Frame`] holds one channel value for each channel of a
+//! [`ChannelLayout`], in layout order.  Per-frame conversion between channel
+//! formats is done by mapping a channel [`Conversion`] over each channel:
+//!
+//! ```rust
+//! use p_chan::{
+//!     frame::{ChannelLayout, Frame, Speaker},
+//!     ops::Conversion,
+//!     signed::{Ch16, Ch32},
+//! };
+//!
+//! let layout = ChannelLayout::STEREO;
+//! let frame = Frame([Ch16::MAX, Ch16::MIN]);
+//! let frame = frame.map(Conversion::<Ch16, Ch32>::convert);
+//!
+//! assert_eq!(frame, Frame([Ch32::MAX, Ch32::MIN]));
+//! assert_eq!(layout.channels(), 2);
+//! assert_eq!(layout.index(Speaker::FrontRight), Some(1));
+//! ```
+//!
+//! Multichannel buffers are either interleaved (frame after frame, such as
+//! `LRLRLR`), or planar (a slice for each channel, such as `LLL` and `RRR`).
+//! [`interleave`] and [`deinterleave`] convert between them, while converting
+//! the channel format in the same pass:
+//!
+//! ```rust
+//! use p_chan::{
+//!     frame,
+//!     ops::Conversion,
+//!     signed::{Ch16, Ch32},
+//! };
+//!
+//! let interleaved = [Ch16::MAX, Ch16::MIN, Ch16::MAX, Ch16::MIN];
+//! let (mut left, mut right) = ([Ch32::new(0.0); 2], [Ch32::new(0.0); 2]);
+//!
+//! frame::deinterleave(
+//!     &interleaved,
+//!     &mut [&mut left, &mut right],
+//!     Conversion::<Ch16, Ch32>::convert,
+//! );
+//!
+//! assert_eq!((left, right), ([Ch32::MAX; 2], [Ch32::MIN; 2]));
+//! ```
+//!
+//! [`Conversion`]: crate::ops::Conversion
+
+use core::ops::{Add, Sub};
+
+/// Speaker position (with its `WAVE_FORMAT_EXTENSIBLE` channel mask bit)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum Speaker {
+    /// Front left
+    FrontLeft = 0x1,
+    /// Front right
+    FrontRight = 0x2,
+    /// Front center
+    FrontCenter = 0x4,
+    /// Low frequency effects (subwoofer)
+    LowFrequency = 0x8,
+    /// Back (rear) left
+    BackLeft = 0x10,
+    /// Back (rear) right
+    BackRight = 0x20,
+    /// Front left of center
+    FrontLeftOfCenter = 0x40,
+    /// Front right of center
+    FrontRightOfCenter = 0x80,
+    /// Back (rear) center
+    BackCenter = 0x100,
+    /// Side left (surround left)
+    SideLeft = 0x200,
+    /// Side right (surround right)
+    SideRight = 0x400,
+    /// Top center
+    TopCenter = 0x800,
+    /// Top front left
+    TopFrontLeft = 0x1000,
+    /// Top front center
+    TopFrontCenter = 0x2000,
+    /// Top front right
+    TopFrontRight = 0x4000,
+    /// Top back left
+    TopBackLeft = 0x8000,
+    /// Top back center
+    TopBackCenter = 0x1_0000,
+    /// Top back right
+    TopBackRight = 0x2_0000,
+}
+
+impl Speaker {
+    /// All speaker positions, in channel order
+    pub const ALL: [Self; 18] = [
+        Self::FrontLeft,
+        Self::FrontRight,
+        Self::FrontCenter,
+        Self::LowFrequency,
+        Self::BackLeft,
+        Self::BackRight,
+        Self::FrontLeftOfCenter,
+        Self::FrontRightOfCenter,
+        Self::BackCenter,
+        Self::SideLeft,
+        Self::SideRight,
+        Self::TopCenter,
+        Self::TopFrontLeft,
+        Self::TopFrontCenter,
+        Self::TopFrontRight,
+        Self::TopBackLeft,
+        Self::TopBackCenter,
+        Self::TopBackRight,
+    ];
+
+    /// Get the `WAVE_FORMAT_EXTENSIBLE` channel mask bit.
+    pub const fn mask(self) -> u32 {
+        self as u32
+    }
+}
+
+/// Channel layout
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ChannelLayout {
+    /// Speaker positions, as a `WAVE_FORMAT_EXTENSIBLE` channel mask
+    ///
+    /// Channels are ordered by mask bit (the same as SMPTE ordering).  Bits
+    /// without a speaker position are ignored.
+    Speakers(u32),
+    /// Ambisonics of an order, with (order + 1)² channels in ACN order
+    Ambisonic(u16),
+}
+
+impl ChannelLayout {
+    /// Mono (1.0)
+    pub const MONO: Self = Self::speakers(&[Speaker::FrontCenter]);
+    /// Quadraphonic (4.0)
+    pub const QUAD: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::BackLeft,
+        Speaker::BackRight,
+    ]);
+    /// Stereo (2.0)
+    pub const STEREO: Self =
+        Self::speakers(&[Speaker::FrontLeft, Speaker::FrontRight]);
+    /// Stereo with low frequency effects (2.1)
+    pub const STEREO_2_1: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::LowFrequency,
+    ]);
+    /// Left, center, and right (3.0)
+    pub const SURROUND_3_0: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+    ]);
+    /// 5.1 surround (with side speakers)
+    pub const SURROUND_5_1: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::LowFrequency,
+        Speaker::SideLeft,
+        Speaker::SideRight,
+    ]);
+    /// 5.1 surround (with back speakers)
+    pub const SURROUND_5_1_BACK: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::LowFrequency,
+        Speaker::BackLeft,
+        Speaker::BackRight,
+    ]);
+    /// 7.1 surround
+    pub const SURROUND_7_1: Self = Self::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::LowFrequency,
+        Speaker::BackLeft,
+        Speaker::BackRight,
+        Speaker::SideLeft,
+        Speaker::SideRight,
+    ]);
+
+    /// Create a speaker layout from a list of speaker positions.
+    pub const fn speakers(speakers: &[Speaker]) -> Self {
+        let mut mask = 0;
+        let mut i = 0;
+
+        while i < speakers.len() {
+            mask |= speakers[i].mask();
+            i += 1;
+        }
+
+        Self::Speakers(mask)
+    }
+
+    /// Get the number of channels.
+    pub const fn channels(self) -> usize {
+        match self {
+            Self::Speakers(mask) => (mask & MASK).count_ones() as usize,
+            Self::Ambisonic(order) => {
+                let order = order as usize;
+
+                (order + 1) * (order + 1)
+            }
+        }
+    }
+
+    /// Check if the layout contains a speaker position.
+    pub const fn contains(self, speaker: Speaker) -> bool {
+        match self {
+            Self::Speakers(mask) => mask & speaker.mask() != 0,
+            Self::Ambisonic(_) => false,
+        }
+    }
+
+    /// Get the channel index of a speaker position.
+    pub const fn index(self, speaker: Speaker) -> Option<usize> {
+        match self {
+            Self::Speakers(mask) if self.contains(speaker) => {
+                let below = mask & MASK & (speaker.mask() - 1);
+
+                Some(below.count_ones() as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the speaker position of a channel index.
+    pub const fn speaker(self, index: usize) -> Option<Speaker> {
+        let Self::Speakers(mask) = self else {
+            return None;
+        };
+        let mut remaining = index;
+        let mut i = 0;
+
+        while i < Speaker::ALL.len() {
+            let speaker = Speaker::ALL[i];
+
+            if mask & speaker.mask() != 0 {
+                if remaining == 0 {
+                    return Some(speaker);
+                }
+
+                remaining -= 1;
+            }
+
+            i += 1;
+        }
+
+        None
+    }
+}
+
+/// Mask of all speaker positions
+const MASK: u32 = 0x3_ffff;
+
+/// Audio frame (one channel value for each channel)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[repr(transparent)]
+pub struct Frame<C, const N: usize>(pub [C; N]);
+
+impl<C, const N: usize> Frame<C, N> {
+    /// Create a new frame.
+    pub const fn new(chans: [C; N]) -> Self {
+        Self(chans)
+    }
+
+    /// Get the inner channel values.
+    pub fn into_inner(self) -> [C; N] {
+        self.0
+    }
+
+    /// Apply `f` to each channel (for example, to convert between channel
+    /// formats).
+    pub fn map<D>(self, f: impl FnMut(C) -> D) -> Frame<D, N> {
+        Frame(self.0.map(f))
+    }
+}
+
+impl<C: Default, const N: usize> Default for Frame<C, N> {
+    fn default() -> Self {
+        Self(core::array::from_fn(|_| C::default()))
+    }
+}
+
+impl<C, const N: usize> From<[C; N]> for Frame<C, N> {
+    fn from(chans: [C; N]) -> Self {
+        Self(chans)
+    }
+}
+
+impl<C, const N: usize> From<Frame<C, N>> for [C; N] {
+    fn from(frame: Frame<C, N>) -> Self {
+        frame.0
+    }
+}
+
+impl<C: Copy + Add<Output = C>, const N: usize> Add for Frame<C, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<C: Copy + Sub<Output = C>, const N: usize> Sub for Frame<C, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(core::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+/// Interleave planar channels (a slice for each channel) into `interleaved`,
+/// applying `convert` to each channel value (use [`core::convert::identity`]
+/// to only interleave).
+///
+/// # Panics
+///
+/// Panics if the planar slices have different lengths, or `interleaved` isn't
+/// their length multiplied by the number of channels.
+pub fn interleave<T: Copy, U>(
+    planar: &[&[T]],
+    interleaved: &mut [U],
+    mut convert: impl FnMut(T) -> U,
+) {
+    let frames = planar.first().map_or(0, |chans| chans.len());
+
+    assert!(
+        planar.iter().all(|chans| chans.len() == frames)
+            && interleaved.len() == frames * planar.len(),
+        "buffer length mismatch",
+    );
+
+    for (channel, chans) in planar.iter().enumerate() {
+        for (frame, &chan) in chans.iter().enumerate() {
+            interleaved[frame * planar.len() + channel] = convert(chan);
+        }
+    }
+}
+
+/// Deinterleave `interleaved` into planar channels (a slice for each channel),
+/// applying `convert` to each channel value (use [`core::convert::identity`]
+/// to only deinterleave).
+///
+/// # Panics
+///
+/// Panics if the planar slices have different lengths, or `interleaved` isn't
+/// their length multiplied by the number of channels.
+pub fn deinterleave<T: Copy, U>(
+    interleaved: &[T],
+    planar: &mut [&mut [U]],
+    mut convert: impl FnMut(T) -> U,
+) {
+    let frames = planar.first().map_or(0, |chans| chans.len());
+    let channels = planar.len();
+
+    assert!(
+        planar.iter().all(|chans| chans.len() == frames)
+            && interleaved.len() == frames * channels,
+        "buffer length mismatch",
+    );
+
+    for (channel, chans) in planar.iter_mut().enumerate() {
+        for (frame, chan) in chans.iter_mut().enumerate() {
+            *chan = convert(interleaved[frame * channels + channel]);
+        }
+    }
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! frame_channel {
+    ($([$($generics: tt)*] $ch: ty),* $(,)?) => {
+        $(
+            impl<$($generics)* const N: usize> Frame<$ch, N> {
+                /// Calculates the middle point of `self` and `rhs` for each
+                /// channel (mixing at half gain).
+                pub const fn midpoint(self, rhs: Self) -> Self {
+                    let mut chans = self.0;
+                    let mut i = 0;
+
+                    while i < N {
+                        chans[i] = chans[i].midpoint(rhs.0[i]);
+                        i += 1;
+                    }
+
+                    Self(chans)
+                }
+
+                /// Clamps each channel between the channels of `min` and
+                /// `max`.
+                ///
+                /// # Panics
+                ///
+                /// Panics if any channel of `min` is greater than the channel
+                /// of `max`.
+                pub const fn clamp(self, min: Self, max: Self) -> Self {
+                    let mut chans = self.0;
+                    let mut i = 0;
+
+                    while i < N {
+                        chans[i] = chans[i].clamp(min.0[i], max.0[i]);
+                        i += 1;
+                    }
+
+                    Self(chans)
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "unsigned")]
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN};
+
+    frame_channel!(
+        [] Ch8,
+        [] Ch10,
+        [] Ch12,
+        [] Ch16,
+        [] Ch24,
+        [const BITS: u32,] ChN<BITS>,
+        [] Bf16,
+        [] Ch32,
+        [] Ch64,
+    );
+}
+
+#[cfg(feature = "signed")]
+mod signed {
+    use super::*;
+    use crate::signed::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN};
+
+    frame_channel!(
+        [] Ch8,
+        [] Ch10,
+        [] Ch12,
+        [] Ch16,
+        [] Ch24,
+        [const BITS: u32,] ChN<BITS>,
+        [] Bf16,
+        [] Ch32,
+        [] Ch64,
+    );
+}
@@ -0,0 +1,249 @@
+//! Gain (amplification) in decibels or linear amplitude
+//!
+//! A [`Gain`] is applied to channel values with [`Amplification`], which
+//! saturates integer channels.  Floating-point channels can exceed full scale.
+//! Unsigned channels are amplified around their middle value (silence).
+//!
+//! ```rust
+//! use p_chan::{gain::Gain, ops::Amplification, signed::Ch16};
+//!
+//! let chan = Amplification(Ch16::new(10_000)).amplify(Gain::from_db(-6.0));
+//!
+//! assert_eq!(chan, Ch16::new(5_012));
+//!
+//! let chan = Amplification(Ch16::new(30_000)).amplify(Gain::new(2.0));
+//!
+//! assert_eq!(chan, Ch16::MAX);
+//! ```
+//!
+//! Changing gain suddenly causes audible clicks ("zipper noise"), so gain
+//! changes can be smoothed over a number of frames with a [`Ramp`]:
+//!
+//! ```rust
+//! use p_chan::{
+//!     gain::{Gain, Ramp},
+//!     ops::Amplification,
+//!     signed::Ch32,
+//! };
+//!
+//! let mut ramp = Ramp::new(Gain::UNITY);
+//! let mut buffer = [Ch32::new(1.0); 4];
+//!
+//! ramp.set(Gain::SILENCE, 4);
+//! Amplification(&mut buffer[..]).ramp(&mut ramp, 1);
+//!
+//! assert_eq!(buffer.map(Ch32::into_inner), [0.75, 0.5, 0.25, 0.0]);
+//! ```
+
+use crate::math;
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+use crate::ops::Amplification;
+
+/// Gain as a linear amplitude factor
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Default)]
+pub struct Gain(f32);
+
+impl Gain {
+    /// Silence (-∞ dB)
+    pub const SILENCE: Self = Self(0.0);
+    /// No change (0 dB)
+    pub const UNITY: Self = Self(1.0);
+
+    /// Create a new gain from a linear amplitude factor.
+    pub const fn new(linear: f32) -> Self {
+        Self(linear)
+    }
+
+    /// Create a new gain from decibels (20 log₁₀ of the amplitude factor).
+    pub const fn from_db(db: f32) -> Self {
+        if db == f32::NEG_INFINITY {
+            return Self::SILENCE;
+        }
+
+        Self(math::exp(db as f64 * core::f64::consts::LN_10 / 20.0) as f32)
+    }
+
+    /// Get the linear amplitude factor.
+    pub const fn into_inner(self) -> f32 {
+        self.0
+    }
+
+    /// Get the gain in decibels (-∞ for silence).
+    pub const fn to_db(self) -> f32 {
+        (20.0 * math::log10(self.0.abs() as f64)) as f32
+    }
+}
+
+/// Smoothed (linear) gain ramp
+// Not `Copy`, so that the ramp state isn't duplicated implicitly
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ramp {
+    current: f64,
+    target: f64,
+    step: f64,
+    /// Remaining frames until the target is reached
+    remaining: usize,
+}
+
+impl Ramp {
+    /// Create a new ramp, starting at a constant gain.
+    pub const fn new(gain: Gain) -> Self {
+        Self {
+            current: gain.0 as f64,
+            target: gain.0 as f64,
+            step: 0.0,
+            remaining: 0,
+        }
+    }
+
+    /// Ramp from the current gain to `target` over `frames` frames.
+    pub const fn set(&mut self, target: Gain, frames: usize) {
+        self.target = target.0 as f64;
+        self.remaining = frames;
+
+        if frames == 0 {
+            self.current = self.target;
+            self.step = 0.0;
+        } else {
+            self.step = (self.target - self.current) / frames as f64;
+        }
+    }
+
+    /// Get the current gain.
+    pub const fn gain(&self) -> Gain {
+        Gain(self.current as f32)
+    }
+
+    /// Get the target gain.
+    pub const fn target(&self) -> Gain {
+        Gain(self.target as f32)
+    }
+
+    /// Check if the ramp has reached its target.
+    pub const fn is_done(&self) -> bool {
+        self.remaining == 0
+    }
+
+    /// Advance by one frame, returning the new gain.
+    #[cfg(any(feature = "signed", feature = "unsigned"))]
+    const fn advance(&mut self) -> f64 {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            // Land exactly on the target
+            self.current = if self.remaining == 0 {
+                self.target
+            } else {
+                self.current + self.step
+            };
+        }
+
+        self.current
+    }
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! amplification {
+    ($($kind: ident $ch: ident: $p: ty, $offset: expr);* $(;)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Amplification<$ch> {
+                /// Amplify by a gain.
+                pub const fn amplify(self, gain: Gain) -> $ch {
+                    amplification!(
+                        @apply $kind $ch: $p, $offset, self.0, gain.0 as f64
+                    )
+                }
+            }
+
+            #[allow(trivial_numeric_casts)]
+            impl Amplification<&mut [$ch]> {
+                /// Amplify each channel value by a gain.
+                pub const fn amplify(self, gain: Gain) {
+                    let mut i = 0;
+
+                    while i < self.0.len() {
+                        self.0[i] = Amplification(self.0[i]).amplify(gain);
+                        i += 1;
+                    }
+                }
+
+                /// Amplify by a gain ramp, advancing the ramp once per frame
+                /// of `channels` interleaved channel values.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `channels` is zero.
+                pub const fn ramp(self, ramp: &mut Ramp, channels: usize) {
+                    assert!(channels != 0, "zero channels");
+
+                    let mut i = 0;
+
+                    while i < self.0.len() {
+                        let gain = if i % channels == 0 {
+                            ramp.advance()
+                        } else {
+                            ramp.current
+                        };
+
+                        self.0[i] = amplification!(
+                            @apply $kind $ch: $p, $offset, self.0[i], gain
+                        );
+                        i += 1;
+                    }
+                }
+            }
+        )*
+    };
+    (
+        @apply int $ch: ident: $p: ty, $offset: expr, $chan: expr,
+        $gain: expr
+    ) => {{
+        let min = $ch::MIN.into_inner() as f64;
+        let max = $ch::MAX.into_inner() as f64;
+        let value = ($chan.into_inner() as f64 - $offset) * $gain + $offset;
+
+        // Round to nearest, and saturate
+        $ch::new(math::round(value).clamp(min, max) as $p)
+    }};
+    (
+        @apply float $ch: ident: $p: ty, $offset: expr, $chan: expr,
+        $gain: expr
+    ) => {{
+        let value = ($chan.into_inner() as f64 - $offset) * $gain + $offset;
+
+        $ch::new(value as $p)
+    }};
+}
+
+#[cfg(feature = "signed")]
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    amplification!(
+        int Ch8: i8, 0.0;
+        int Ch10: i16, 0.0;
+        int Ch12: i16, 0.0;
+        int Ch16: i16, 0.0;
+        int Ch24: i32, 0.0;
+        float Ch32: f32, 0.0;
+        float Ch64: f64, 0.0;
+    );
+}
+
+#[cfg(feature = "unsigned")]
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    amplification!(
+        int Ch8: u8, 128.0;
+        int Ch10: u16, 512.0;
+        int Ch12: u16, 2_048.0;
+        int Ch16: u16, 32_768.0;
+        int Ch24: u32, 8_388_608.0;
+        float Ch32: f32, 0.5;
+        float Ch64: f64, 0.5;
+    );
+}
@@ -0,0 +1,58 @@
+//! HDR-encoded channel newtypes
+//!
+//! These store full-range 10-bit or 12-bit non-linear code values, and convert
+//! to and from linear-light unsigned floating-point channels with
+//! [`Conversion`](crate::ops::Conversion).
+
+pub mod pq {
+    //! Perceptual quantizer (SMPTE ST 2084) encoded channel newtypes
+    //!
+    //! Linear light is absolute display luminance, where `1.0` is
+    //! 10\_000 cd/m².
+
+    use crate::{
+        transfer::Transfer,
+        unsigned::{ChN, midpoint},
+    };
+
+    ch_int!(
+        (Ch10, u16, u32, ChN<10>, midpoint! {}),
+        doc = "10-bit PQ-encoded (0 to 1\\_023) unsigned integer channel value",
+    );
+
+    ch_int!(
+        (Ch12, u16, u32, ChN<12>, midpoint! {}),
+        doc = "12-bit PQ-encoded (0 to 4\\_095) unsigned integer channel value",
+    );
+
+    transfer!(Ch10, u16, 1_023.0, Transfer::Pq);
+    transfer!(Ch12, u16, 4_095.0, Transfer::Pq);
+}
+
+pub mod hlg {
+    //! Hybrid log-gamma (ARIB STD-B67 / ITU-R BT.2100) encoded channel
+    //! newtypes
+    //!
+    //! Linear light is relative scene luminance (0 to 1), before the HLG OOTF
+    //! is applied for display.
+
+    use crate::{
+        transfer::Transfer,
+        unsigned::{ChN, midpoint},
+    };
+
+    ch_int!(
+        (Ch10, u16, u32, ChN<10>, midpoint! {}),
+        doc =
+            "10-bit HLG-encoded (0 to 1\\_023) unsigned integer channel value",
+    );
+
+    ch_int!(
+        (Ch12, u16, u32, ChN<12>, midpoint! {}),
+        doc =
+            "12-bit HLG-encoded (0 to 4\\_095) unsigned integer channel value",
+    );
+
+    transfer!(Ch10, u16, 1_023.0, Transfer::Hlg);
+    transfer!(Ch12, u16, 4_095.0, Transfer::Hlg);
+}
@@ -2,11 +2,13 @@
 //!
 //! Each module is enabled with a feature by the same name.
 //!
-//! The types provided by each module are `Ch8`, `Ch12`, `Ch16`, `Ch24` for
-//! integers, and `Ch32` and `Ch64` for floating-point.  Integer channels can
-//! not exceed the range of their minimum and maximum values, while
-//! floating-point channels can.  Floating-point channels can only ever be
-//! normal numbers or infinity.
+//! The types provided by each module are `Ch8`, `Ch10`, `Ch12`, `Ch16`,
+//! `Ch24` for integers (with `ChN` for any other bit depth), and `Bf16`, `Ch32`
+//! and `Ch64` for floating-point. The fixed-depth integer channels wrap the
+//! smallest primitive that fits, with the range and conversions of `ChN`.
+//! Integer channels can not exceed the range of their minimum and maximum
+//! values, while floating-point channels can.
+//! Floating-point channels can only ever be normal numbers or infinity.
 
 #![no_std]
 #![deny(
@@ -33,11 +35,52 @@
 )]
 
 mod conversions;
+mod math;
 #[cfg(any(feature = "unsigned", feature = "signed"))]
 #[macro_use]
 mod macros;
 pub mod chan;
+#[cfg(feature = "clip")]
+pub mod clip;
+#[cfg(feature = "composite")]
+pub mod composite;
+#[cfg(feature = "dsd")]
+pub mod dsd;
+#[cfg(feature = "filter")]
+pub mod filter;
+#[cfg(feature = "frame")]
+pub mod frame;
+#[cfg(feature = "gain")]
+pub mod gain;
+#[cfg(feature = "hdr")]
+pub mod hdr;
+#[cfg(feature = "loudness")]
+pub mod loudness;
+#[cfg(feature = "meter")]
+pub mod meter;
+#[cfg(feature = "mix")]
+pub mod mix;
+#[cfg(feature = "model")]
+pub mod model;
 pub mod ops;
+#[cfg(feature = "packed")]
+pub mod packed;
+#[cfg(feature = "pan")]
+pub mod pan;
+#[cfg(feature = "pixel")]
+pub mod pixel;
+#[cfg(feature = "planar")]
+pub mod planar;
+#[cfg(feature = "range")]
+pub mod range;
+#[cfg(feature = "resample")]
+pub mod resample;
+#[cfg(feature = "srgb")]
+pub mod srgb;
+#[cfg(feature = "transfer")]
+pub mod transfer;
+#[cfg(feature = "ycbcr")]
+pub mod ycbcr;
 
 #[cfg(feature = "signed")]
 pub mod signed {
@@ -61,25 +104,41 @@ pub mod signed {
     }
 
     ch_int!(
-        (Ch8, i8, i16, core::convert::identity, midpoint! {}),
+        (Ch8, i8, i16, ChN<8>, midpoint! {}),
         doc = "8-bit signed integer (-128 to 127) channel value",
     );
 
     ch_int!(
-        (Ch12, i16, i32, normalize_ch12, midpoint! {}),
+        (Ch10, i16, i32, ChN<10>, midpoint! {}),
+        doc = "10-bit signed integer (-512 to 511) channel value",
+    );
+
+    ch_int!(
+        (Ch12, i16, i32, ChN<12>, midpoint! {}),
         doc = "12-bit signed integer (-2\\_048 to 2\\_047) channel value",
     );
 
     ch_int!(
-        (Ch16, i16, i32, core::convert::identity, midpoint! {}),
+        (Ch16, i16, i32, ChN<16>, midpoint! {}),
         doc = "16-bit signed integer (-32\\_768 to 32\\_767) channel value",
     );
 
     ch_int!(
-        (Ch24, i32, i64, normalize_ch24, midpoint! {}),
+        (Ch24, i32, i64, ChN<24>, midpoint! {}),
         doc = "24-bit signed integer (-8\\_388\\_608 to 8\\_388\\_607) channel value",
     );
 
+    ch_n!(
+        (ChN, i32, i64, normalize_n, midpoint! {}),
+        (i32::MIN >> (32 - BITS), i32::MAX >> (32 - BITS)),
+        doc = "Signed integer channel value of any bit depth (`BITS` from 1 to 32)",
+    );
+
+    ch_bfloat!(
+        (Bf16, -1.0, 0.0),
+        doc = "16-bit brain float (-1 to 1) channel value",
+    );
+
     ch_float!(
         (Ch32, f32, crate::conversions::normalize_f32, -1.0, 0.0),
         doc = "32-bit float (-1 to 1) channel value",
@@ -90,28 +149,14 @@ pub mod signed {
         doc = "64-bit float (-1 to 1) channel value",
     );
 
-    const fn normalize_ch12(mut chan: i16) -> i16 {
-        if chan > 2_i16.pow(11) - 1 {
-            chan = 2_i16.pow(11) - 1;
-        }
-
-        if chan < -2_i16.pow(11) {
-            chan = -2_i16.pow(11);
-        }
-
-        chan
-    }
-
-    const fn normalize_ch24(mut chan: i32) -> i32 {
-        if chan > 2_i32.pow(23) - 1 {
-            chan = 2_i32.pow(23) - 1;
+    const fn normalize_n(chan: i32, min: i32, max: i32) -> i32 {
+        if chan > max {
+            max
+        } else if chan < min {
+            min
+        } else {
+            chan
         }
-
-        if chan < -2_i32.pow(23) {
-            chan = -2_i32.pow(23);
-        }
-
-        chan
     }
 }
 
@@ -131,26 +176,45 @@ pub mod unsigned {
         };
     }
 
+    #[cfg(feature = "transfer")]
+    pub(crate) use midpoint;
+
     ch_int!(
-        (Ch8, u8, u16, core::convert::identity, midpoint! {}),
+        (Ch8, u8, u16, ChN<8>, midpoint! {}),
         doc = "8-bit (0 to 255) unsigned integer channel value",
     );
 
     ch_int!(
-        (Ch12, u16, u32, normalize_ch12, midpoint! {}),
+        (Ch10, u16, u32, ChN<10>, midpoint! {}),
+        doc = "10-bit unsigned integer (0 to 1\\_023) channel value",
+    );
+
+    ch_int!(
+        (Ch12, u16, u32, ChN<12>, midpoint! {}),
         doc = "12-bit unsigned integer (0 to 4\\_095) channel value",
     );
 
     ch_int!(
-        (Ch16, u16, u32, core::convert::identity, midpoint! {}),
+        (Ch16, u16, u32, ChN<16>, midpoint! {}),
         doc = "16-bit unsigned integer (0 to 65\\_535) channel value",
     );
 
     ch_int!(
-        (Ch24, u32, u64, normalize_ch24, midpoint! {}),
+        (Ch24, u32, u64, ChN<24>, midpoint! {}),
         doc = "24-bit unsigned integer (0 to 16\\_777\\_215) channel value",
     );
 
+    ch_n!(
+        (ChN, u32, u64, normalize_n, midpoint! {}),
+        (0, u32::MAX >> (32 - BITS)),
+        doc = "Unsigned integer channel value of any bit depth (`BITS` from 1 to 32)",
+    );
+
+    ch_bfloat!(
+        (Bf16, 0.0, 0.5),
+        doc = "16-bit brain float (0 to 1) channel value",
+    );
+
     ch_float!(
         (Ch32, f32, crate::conversions::normalize_f32, 0.0, 0.5),
         doc = "32-bit float (0 to 1) channel value",
@@ -161,11 +225,7 @@ pub mod unsigned {
         doc = "64-bit float (0 to 1) channel value",
     );
 
-    const fn normalize_ch12(chan: u16) -> u16 {
-        (chan << 4) >> 4
-    }
-
-    const fn normalize_ch24(chan: u32) -> u32 {
-        (chan << 8) >> 8
+    const fn normalize_n(chan: u32, _min: u32, max: u32) -> u32 {
+        chan & max
     }
 }
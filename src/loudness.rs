@@ -0,0 +1,434 @@
+//! Loudness measurement (ITU-R BS.1770 and EBU R128)
+//!
+//! A [`Loudness`] meter K-weights each channel of a frame, weights it by its
+//! speaker position (surround channels are weighted by +1.5 dB, and the
+//! low-frequency effects channel is ignored), and measures:
+//!
+//!  - Momentary loudness (400 ms window)
+//!  - Short-term loudness (3 s window)
+//!  - Integrated loudness (over all gated 400 ms blocks, approximating BS.1770)
+//!  - Loudness range (distribution of gated short-term loudness, approximating
+//!    EBU Tech 3342)
+//!
+//! Blocks are kept in a histogram of 0.1 LU bins rather than one by one, so
+//! the relative gates apply to whole bins: blocks up to 0.1 LU below a
+//! relative gate can be counted, and loudness range percentiles are rounded
+//! to the center of their bin.
+//!
+//! Loudness is in LUFS (loudness units relative to full scale), and loudness
+//! range is in LU.  Integer channels are scaled so that the minimum value is
+//! exactly full scale.
+//!
+//! ```rust
+//! use p_chan::{
+//!     frame::{ChannelLayout, Frame},
+//!     loudness::Loudness,
+//!     signed::Ch32,
+//! };
+//!
+//! let mut meter =
+//!     Loudness::<Ch32>::new(ChannelLayout::STEREO, 48_000).unwrap();
+//!
+//! // 1 kHz sine wave at -23 dBFS on both channels, for 1 second
+//! for i in 0..48_000 {
+//!     let phase = core::f32::consts::TAU * i as f32 / 48.0;
+//!     let chan = Ch32::new(0.070_794_58 * phase.sin());
+//!
+//!     meter.process(&[Frame([chan, chan])]);
+//! }
+//!
+//! assert!((meter.momentary() + 23.0).abs() < 0.1);
+//! assert!((meter.integrated() + 23.0).abs() < 0.1);
+//! ```
+
+use core::{f64::consts::PI, marker::PhantomData};
+
+use crate::{
+    filter::Coefficients,
+    frame::{ChannelLayout, Frame, Speaker},
+    math,
+};
+
+/// Maximum number of channels (one for each speaker position)
+const MAX_CHANNELS: usize = Speaker::ALL.len();
+/// Number of 100 ms sub-blocks in a momentary block
+const MOMENTARY: usize = 4;
+/// Number of 100 ms sub-blocks in a short-term block
+const SHORT_TERM: usize = 30;
+/// Absolute gate (in LUFS)
+const ABSOLUTE_GATE: f64 = -70.0;
+/// Relative gate for integrated loudness (in LU)
+const INTEGRATED_GATE: f64 = -10.0;
+/// Relative gate for loudness range (in LU)
+const RANGE_GATE: f64 = -20.0;
+/// Number of histogram bins (from the absolute gate to +30 LUFS)
+const BINS: usize = 1_000;
+/// Histogram bins per LU
+const BINS_PER_LU: f64 = 10.0;
+
+/// Create the K-weighting pre-filter (high shelf).
+const fn pre_filter(rate: u32) -> Coefficients {
+    let f0 = 1_681.974_450_955_533;
+    let gain = 3.999_843_853_973_347;
+    let q = 0.707_175_236_955_419_6;
+    let k = tan(PI * f0 / rate as f64);
+    let vh = math::pow(10.0, gain / 20.0);
+    let vb = math::pow(vh, 0.499_666_774_154_541_6);
+
+    Coefficients::new(
+        [
+            vh + vb * k / q + k * k,
+            2.0 * (k * k - vh),
+            vh - vb * k / q + k * k,
+        ],
+        [
+            1.0 + k / q + k * k,
+            2.0 * (k * k - 1.0),
+            1.0 - k / q + k * k,
+        ],
+    )
+}
+
+/// Create the K-weighting RLB filter (high pass).
+const fn rlb_filter(rate: u32) -> Coefficients {
+    let f0 = 38.135_470_876_024_44;
+    let q = 0.500_327_037_323_877_3;
+    let k = tan(PI * f0 / rate as f64);
+    let a0 = 1.0 + k / q + k * k;
+
+    // Numerator isn't normalized by a0
+    Coefficients::new(
+        [a0, -2.0 * a0, a0],
+        [a0, 2.0 * (k * k - 1.0), 1.0 - k / q + k * k],
+    )
+}
+
+/// Get the tangent.
+const fn tan(x: f64) -> f64 {
+    let (sin, cos) = math::sin_cos(x);
+
+    sin / cos
+}
+
+/// Convert mean square energy to loudness (in LUFS).
+const fn loudness(energy: f64) -> f64 {
+    -0.691 + 10.0 * math::log10(energy)
+}
+
+/// Get the channel weight of a speaker position.
+const fn weight(speaker: Speaker) -> f64 {
+    match speaker {
+        Speaker::LowFrequency => 0.0,
+        Speaker::BackLeft
+        | Speaker::BackRight
+        | Speaker::SideLeft
+        | Speaker::SideRight => 1.41,
+        _ => 1.0,
+    }
+}
+
+/// Histogram of block loudness (above the absolute gate)
+#[derive(Clone, Debug)]
+struct Histogram {
+    counts: [u32; BINS],
+    energies: [f64; BINS],
+}
+
+impl Histogram {
+    const fn new() -> Self {
+        Self {
+            counts: [0; BINS],
+            energies: [0.0; BINS],
+        }
+    }
+
+    /// Get the bin of a loudness above the absolute gate.
+    const fn bin(loudness: f64) -> usize {
+        let bin = math::floor((loudness - ABSOLUTE_GATE) * BINS_PER_LU);
+
+        if bin < BINS as f64 {
+            bin as usize
+        } else {
+            BINS - 1
+        }
+    }
+
+    /// Get the loudness at the center of a bin.
+    const fn center(bin: usize) -> f64 {
+        ABSOLUTE_GATE + (bin as f64 + 0.5) / BINS_PER_LU
+    }
+
+    /// Add a block (ignored if not above the absolute gate).
+    const fn add(&mut self, energy: f64) {
+        let loudness = loudness(energy);
+
+        if loudness > ABSOLUTE_GATE {
+            let bin = Self::bin(loudness);
+
+            self.counts[bin] += 1;
+            self.energies[bin] += energy;
+        }
+    }
+
+    /// Get the bin containing the relative gate (`None` if empty).
+    const fn gate(&self, relative: f64) -> Option<usize> {
+        let (count, energy) = self.sum(0);
+
+        if count == 0 {
+            return None;
+        }
+
+        let gate = loudness(energy / count as f64) + relative;
+
+        Some(if gate > ABSOLUTE_GATE {
+            Self::bin(gate)
+        } else {
+            0
+        })
+    }
+
+    /// Get the number and total energy of blocks from a bin upwards.
+    const fn sum(&self, from: usize) -> (u64, f64) {
+        let (mut count, mut energy) = (0, 0.0);
+        let mut bin = from;
+
+        while bin < BINS {
+            count += self.counts[bin] as u64;
+            energy += self.energies[bin];
+            bin += 1;
+        }
+
+        (count, energy)
+    }
+
+    /// Get the loudness of a percentile of blocks from a bin upwards.
+    const fn percentile(&self, from: usize, count: u64, fraction: f64) -> f64 {
+        let index = math::round((count - 1) as f64 * fraction) as u64;
+        let mut seen = 0;
+        let mut bin = from;
+
+        while bin < BINS {
+            seen += self.counts[bin] as u64;
+
+            if seen > index {
+                break;
+            }
+
+            bin += 1;
+        }
+
+        Self::center(bin)
+    }
+}
+
+/// Loudness meter
+#[derive(Clone, Debug)]
+pub struct Loudness<C> {
+    channels: usize,
+    weights: [f64; MAX_CHANNELS],
+    shelf: Coefficients,
+    high_pass: Coefficients,
+    /// K-weighting filter state for each channel
+    state: [[[f64; 2]; 2]; MAX_CHANNELS],
+    /// Length of a sub-block (100 ms) in frames
+    length: usize,
+    /// Number of frames in the current sub-block
+    frames: usize,
+    /// Weighted sum of squares in the current sub-block
+    sum: f64,
+    /// Weighted sums of squares of recent sub-blocks (ring buffer)
+    recent: [f64; SHORT_TERM],
+    /// Number of complete sub-blocks
+    complete: u64,
+    /// Momentary blocks for integrated loudness
+    blocks: Histogram,
+    /// Short-term blocks for loudness range
+    short_term: Histogram,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Loudness<C> {
+    /// Create a new loudness meter for a channel layout at a sample rate (in
+    /// Hz).
+    ///
+    /// Returns `None` for ambisonic layouts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sample rate is less than 10 Hz.
+    pub const fn new(layout: ChannelLayout, rate: u32) -> Option<Self> {
+        assert!(rate >= 10, "invalid sample rate");
+
+        if let ChannelLayout::Ambisonic(_) = layout {
+            return None;
+        }
+
+        let mut weights = [0.0; MAX_CHANNELS];
+        let mut i = 0;
+
+        while let Some(speaker) = layout.speaker(i) {
+            weights[i] = weight(speaker);
+            i += 1;
+        }
+
+        Some(Self {
+            channels: layout.channels(),
+            weights,
+            shelf: pre_filter(rate),
+            high_pass: rlb_filter(rate),
+            state: [[[0.0; 2]; 2]; MAX_CHANNELS],
+            length: math::round(rate as f64 / 10.0) as usize,
+            frames: 0,
+            sum: 0.0,
+            recent: [0.0; SHORT_TERM],
+            complete: 0,
+            blocks: Histogram::new(),
+            short_term: Histogram::new(),
+            _chan: PhantomData,
+        })
+    }
+
+    /// Reset to the initial state (forgetting all measurements).
+    pub const fn reset(&mut self) {
+        self.state = [[[0.0; 2]; 2]; MAX_CHANNELS];
+        self.frames = 0;
+        self.sum = 0.0;
+        self.recent = [0.0; SHORT_TERM];
+        self.complete = 0;
+        self.blocks = Histogram::new();
+        self.short_term = Histogram::new();
+    }
+
+    /// Get the momentary loudness (in LUFS) of the last 400 ms.
+    pub const fn momentary(&self) -> f32 {
+        loudness(self.energy(MOMENTARY)) as f32
+    }
+
+    /// Get the short-term loudness (in LUFS) of the last 3 s.
+    pub const fn short_term(&self) -> f32 {
+        loudness(self.energy(SHORT_TERM)) as f32
+    }
+
+    /// Get the integrated loudness (in LUFS).
+    ///
+    /// The relative gate is applied to 0.1 LU histogram bins (see the
+    /// [module documentation](self)).
+    ///
+    /// Returns negative infinity if no block is above the gates.
+    pub const fn integrated(&self) -> f32 {
+        let Some(gate) = self.blocks.gate(INTEGRATED_GATE) else {
+            return f32::NEG_INFINITY;
+        };
+        let (count, energy) = self.blocks.sum(gate);
+
+        loudness(energy / count as f64) as f32
+    }
+
+    /// Get the loudness range (in LU).
+    ///
+    /// The relative gate and percentiles are applied to 0.1 LU histogram bins
+    /// (see the [module documentation](self)).
+    ///
+    /// Returns zero if no short-term block is above the gates.
+    pub const fn range(&self) -> f32 {
+        let Some(gate) = self.short_term.gate(RANGE_GATE) else {
+            return 0.0;
+        };
+        let (count, _) = self.short_term.sum(gate);
+        let low = self.short_term.percentile(gate, count, 0.1);
+        let high = self.short_term.percentile(gate, count, 0.95);
+
+        (high - low) as f32
+    }
+
+    /// Get the mean square energy of the most recent sub-blocks (earlier
+    /// sub-blocks than the first are silent).
+    const fn energy(&self, sub_blocks: usize) -> f64 {
+        let mut sum = 0.0;
+        let mut i = 0;
+
+        while i < sub_blocks {
+            sum += self.recent
+                [(self.complete as usize + SHORT_TERM - 1 - i) % SHORT_TERM];
+            i += 1;
+        }
+
+        sum / (sub_blocks * self.length) as f64
+    }
+
+    /// Measure a frame of samples.
+    const fn push(&mut self, frame: &[f64]) {
+        let mut i = 0;
+
+        while i < frame.len() {
+            let [shelf, high_pass] = &mut self.state[i];
+            let z = self.shelf.filter(shelf, frame[i]);
+            let z = self.high_pass.filter(high_pass, z);
+
+            self.sum += self.weights[i] * z * z;
+            i += 1;
+        }
+
+        self.frames += 1;
+
+        if self.frames < self.length {
+            return;
+        }
+
+        self.recent[self.complete as usize % SHORT_TERM] = self.sum;
+        self.complete += 1;
+        self.frames = 0;
+        self.sum = 0.0;
+
+        // Blocks overlap, stepping by one sub-block
+        if self.complete >= MOMENTARY as u64 {
+            self.blocks.add(self.energy(MOMENTARY));
+        }
+
+        if self.complete >= SHORT_TERM as u64 {
+            self.short_term.add(self.energy(SHORT_TERM));
+        }
+    }
+}
+
+macro_rules! loudness {
+    ($($ch: ident: $scale: expr),* $(,)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Loudness<$ch> {
+                /// Measure frames.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `N` doesn't match the channel layout.
+                pub fn process<const N: usize>(
+                    &mut self,
+                    frames: &[Frame<$ch, N>],
+                ) {
+                    assert!(N == self.channels, "channel count mismatch");
+
+                    for frame in frames {
+                        self.push(
+                            &frame.0.map(|ch| ch.into_inner() as f64 / $scale),
+                        );
+                    }
+                }
+            }
+        )*
+    };
+}
+
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    loudness!(
+        Ch8: 128.0,
+        Ch10: 512.0,
+        Ch12: 2_048.0,
+        Ch16: 32_768.0,
+        Ch24: 8_388_608.0,
+        Ch32: 1.0,
+        Ch64: 1.0,
+    );
+}
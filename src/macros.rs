@@ -1,6 +1,82 @@
+macro_rules! int_arithmetic {
+    (
+        $b: ty,
+        $($op: ident: $checked: ident, $wrapping: ident, $overflowing: ident),*
+        $(,)?
+    ) => {
+        $(
+            #[doc = concat!("Checked integer ", stringify!($op), ".")]
+            ///
+            /// Returns `None` if the result is out of range.
+            pub const fn $checked(self, rhs: Self) -> Option<Self> {
+                match self.$overflowing(rhs) {
+                    (value, false) => Some(value),
+                    (_, true) => None,
+                }
+            }
+
+            #[doc = concat!("Wrapping integer ", stringify!($op), ".")]
+            ///
+            /// Wraps around at the bit depth of the channel.
+            pub const fn $wrapping(self, rhs: Self) -> Self {
+                self.$overflowing(rhs).0
+            }
+
+            #[doc = concat!("Overflowing integer ", stringify!($op), ".")]
+            ///
+            /// Returns the wrapped result, and whether it was out of range.
+            #[allow(trivial_numeric_casts)]
+            pub const fn $overflowing(self, rhs: Self) -> (Self, bool) {
+                // Exact in the wider type, except for unsigned underflow
+                let wide = (self.0 as $b).$wrapping(rhs.0 as $b);
+                let bits = (Self::MAX.0 as $b - Self::MIN.0 as $b).count_ones();
+                let shift = <$b>::BITS - bits;
+                // Sign-extend (or zero-extend) from the channel bit depth
+                let wrapped = (wide << shift) >> shift;
+
+                (Self(wrapped as _), wrapped != wide)
+            }
+        )*
+    };
+}
+
+macro_rules! float_arithmetic {
+    (
+        $($op: ident: $checked: ident, $overflowing: ident $operator: tt),*
+        $(,)?
+    ) => {
+        $(
+            #[doc = concat!("Checked ", stringify!($op), ".")]
+            ///
+            /// Returns `None` if the result is out of range (clipped).
+            pub const fn $checked(self, rhs: Self) -> Option<Self> {
+                match self.$overflowing(rhs) {
+                    (value, false) => Some(value),
+                    (_, true) => None,
+                }
+            }
+
+            #[doc = concat!("Overflowing ", stringify!($op), ".")]
+            ///
+            /// Returns the result clamped to the range, and whether it was
+            /// out of range (clipped).  Floating-point channels clamp rather
+            /// than wrap, so there are no wrapping methods.
+            pub const fn $overflowing(self, rhs: Self) -> (Self, bool) {
+                let value = self.0 $operator rhs.0;
+
+                if value >= Self::MIN.0 && value <= Self::MAX.0 {
+                    (Self::new(value), false)
+                } else {
+                    (Self::new(value.clamp(Self::MIN.0, Self::MAX.0)), true)
+                }
+            }
+        )*
+    };
+}
+
 macro_rules! ch_int {
     (
-        ($ty: ident, $p: ty, $b: ty, $normalize: path, $midpoint: item),
+        ($ty: ident, $p: ty, $b: ty, $n: ty, $midpoint: item),
         $docs: meta $(,)?
     ) => {
         #[$docs]
@@ -34,17 +110,18 @@ macro_rules! ch_int {
             }
         }
 
+        #[allow(trivial_numeric_casts)]
         impl $ty {
             /// Maximum value
-            pub const MAX: Self = Self::new($normalize(<$p>::MAX));
+            pub const MAX: Self = Self(<$n>::MAX.into_inner() as $p);
             /// Middle value
             pub const MID: Self = Self::MAX.midpoint(Self::MIN);
             /// Minimum value
-            pub const MIN: Self = Self::new($normalize(<$p>::MIN));
+            pub const MIN: Self = Self(<$n>::MIN.into_inner() as $p);
 
             /// Create a new channel value.
             pub const fn new(value: $p) -> Self {
-                Self($normalize(value))
+                Self(<$n>::new(value as _).into_inner() as $p)
             }
 
             /// Get the inner primitive channel value.
@@ -75,6 +152,13 @@ macro_rules! ch_int {
 
                 Self(this)
             }
+
+            int_arithmetic!(
+                $b,
+                addition: checked_add, wrapping_add, overflowing_add,
+                subtraction: checked_sub, wrapping_sub, overflowing_sub,
+                multiplication: checked_mul, wrapping_mul, overflowing_mul,
+            );
         }
 
         impl core::ops::Add for $ty {
@@ -82,7 +166,7 @@ macro_rules! ch_int {
 
             #[inline(always)]
             fn add(self, rhs: Self) -> Self {
-                Self($normalize(self.0.saturating_add(rhs.0)))
+                Self::new(self.0.saturating_add(rhs.0))
             }
         }
 
@@ -91,7 +175,7 @@ macro_rules! ch_int {
 
             #[inline(always)]
             fn sub(self, rhs: Self) -> Self {
-                Self($normalize(self.0.saturating_sub(rhs.0)))
+                Self::new(self.0.saturating_sub(rhs.0))
             }
         }
     };
@@ -177,6 +261,12 @@ macro_rules! ch_float {
             pub(crate) const fn normalize(self) -> Self {
                 Self($normalize(self.0))
             }
+
+            float_arithmetic!(
+                addition: checked_add, overflowing_add +,
+                subtraction: checked_sub, overflowing_sub -,
+                multiplication: checked_mul, overflowing_mul *,
+            );
         }
 
         impl core::ops::Add for $ty {
@@ -198,3 +288,289 @@ macro_rules! ch_float {
         }
     };
 }
+
+macro_rules! ch_bfloat {
+    (($ty: ident, $min: literal, $mid: literal), $docs: meta $(,)?) => {
+        #[$docs]
+        #[derive(Copy, Clone, PartialEq, Default)]
+        #[repr(transparent)]
+        pub struct $ty(u16);
+
+        impl core::fmt::Debug for $ty {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                self.into_inner().fmt(f)
+            }
+        }
+
+        impl core::fmt::Display for $ty {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                self.into_inner().fmt(f)
+            }
+        }
+
+        impl From<f32> for $ty {
+            fn from(value: f32) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl From<$ty> for f32 {
+            fn from(chan: $ty) -> Self {
+                chan.into_inner()
+            }
+        }
+
+        impl PartialOrd for $ty {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.into_inner().partial_cmp(&other.into_inner())
+            }
+        }
+
+        impl $ty {
+            /// Maximum value
+            pub const MAX: Self = Self::new(1.0);
+            /// Middle value
+            pub const MID: Self = Self::new($mid);
+            /// Minimum value
+            pub const MIN: Self = Self::new($min);
+
+            /// Create a new channel value (rounding to nearest, ties to
+            /// even).
+            pub const fn new(value: f32) -> Self {
+                let bits = crate::conversions::normalize_f32(value).to_bits();
+                // Round to nearest, ties to even (may round up to infinity)
+                let rounding = 0x7fff + ((bits >> 16) & 1);
+
+                Self(((bits + rounding) >> 16) as u16)
+            }
+
+            /// Create a channel value from its bit representation.
+            ///
+            /// NaN and subnormals flush to zero.
+            pub const fn from_bits(bits: u16) -> Self {
+                Self::new(f32::from_bits((bits as u32) << 16))
+            }
+
+            /// Get the bit representation of the channel value.
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Get the inner primitive channel value.
+            pub const fn into_inner(self) -> f32 {
+                f32::from_bits((self.0 as u32) << 16)
+            }
+
+            /// Calculates the middle point of `self` and `rhs` (clamped).
+            ///
+            /// `midpoint(a, b)` is `(a + b) / 2`.
+            pub const fn midpoint(self, rhs: Self) -> Self {
+                Self::new((self.into_inner() + rhs.into_inner()) / 2.0)
+            }
+
+            /// Returns `max` if `self` is greater than `max`, and `min` if
+            /// `self` is less than `min`. Otherwise this returns `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `min > max`.
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                Self::new(
+                    self.into_inner().clamp(min.into_inner(), max.into_inner()),
+                )
+            }
+        }
+
+        impl core::ops::Add for $ty {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.into_inner() + rhs.into_inner())
+            }
+        }
+
+        impl core::ops::Sub for $ty {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.into_inner() - rhs.into_inner())
+            }
+        }
+    };
+}
+
+macro_rules! ch_n {
+    (
+        ($ty: ident, $p: ty, $b: ty, $normalize: path, $midpoint: item),
+        ($min: expr, $max: expr),
+        $docs: meta $(,)?
+    ) => {
+        #[$docs]
+        ///
+        /// `BITS` must be from 1 to 32, otherwise using the type fails to
+        /// compile.
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+        #[repr(transparent)]
+        pub struct $ty<const BITS: u32>($p);
+
+        impl<const BITS: u32> core::fmt::Debug for $ty<BITS> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+            {
+                core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl<const BITS: u32> core::fmt::Display for $ty<BITS> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+            {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl<const BITS: u32> From<$p> for $ty<BITS> {
+            fn from(value: $p) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<const BITS: u32> From<$ty<BITS>> for $p {
+            fn from(chan: $ty<BITS>) -> Self {
+                chan.0
+            }
+        }
+
+        impl<const BITS: u32> $ty<BITS> {
+            /// Maximum value
+            pub const MAX: Self = {
+                assert!(BITS >= 1 && BITS <= 32, "BITS out of range");
+
+                Self($max)
+            };
+            /// Middle value
+            pub const MID: Self = Self::MAX.midpoint(Self::MIN);
+            /// Minimum value
+            pub const MIN: Self = {
+                assert!(BITS >= 1 && BITS <= 32, "BITS out of range");
+
+                Self($min)
+            };
+
+            /// Create a new channel value.
+            pub const fn new(value: $p) -> Self {
+                Self($normalize(value, Self::MIN.0, Self::MAX.0))
+            }
+
+            /// Get the inner primitive channel value.
+            pub const fn into_inner(self) -> $p {
+                self.0
+            }
+
+            $midpoint
+
+            /// Returns `max` if `self` is greater than `max`, and `min` if
+            /// `self` is less than `min`. Otherwise this returns `self`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `min > max`.
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                let (mut this, min, max) = (self.0, min.0, max.0);
+
+                assert!(min <= max, "min > max");
+
+                if this < min {
+                    this = min;
+                }
+
+                if this > max {
+                    this = max;
+                }
+
+                Self(this)
+            }
+
+            int_arithmetic!(
+                $b,
+                addition: checked_add, wrapping_add, overflowing_add,
+                subtraction: checked_sub, wrapping_sub, overflowing_sub,
+                multiplication: checked_mul, wrapping_mul, overflowing_mul,
+            );
+        }
+
+        impl<const BITS: u32> core::ops::Add for $ty<BITS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self(self.0.saturating_add(rhs.0)).clamp(Self::MIN, Self::MAX)
+            }
+        }
+
+        impl<const BITS: u32> core::ops::Sub for $ty<BITS> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0.saturating_sub(rhs.0)).clamp(Self::MIN, Self::MAX)
+            }
+        }
+    };
+}
+
+/// Convert between a transfer-encoded unsigned integer channel and linear
+/// light unsigned floating-point channels
+#[cfg(feature = "transfer")]
+macro_rules! transfer {
+    ($ty: ident, $p: ty, $max: literal, $transfer: expr) => {
+        impl crate::ops::Conversion<$ty, crate::unsigned::Ch32> {
+            /// Convert between types.
+            pub const fn convert(from: $ty) -> crate::unsigned::Ch32 {
+                let encoded = from.into_inner() as f64 / $max;
+
+                crate::unsigned::Ch32::new($transfer.decode(encoded) as f32)
+            }
+        }
+
+        impl crate::ops::Conversion<$ty, crate::unsigned::Ch64> {
+            /// Convert between types.
+            pub const fn convert(from: $ty) -> crate::unsigned::Ch64 {
+                let encoded = from.into_inner() as f64 / $max;
+
+                crate::unsigned::Ch64::new($transfer.decode(encoded))
+            }
+        }
+
+        impl crate::ops::Conversion<crate::unsigned::Ch32, $ty> {
+            /// Convert between types.
+            pub const fn convert(from: crate::unsigned::Ch32) -> $ty {
+                transfer!(@encode $ty, $p, $max, $transfer, from.into_inner())
+            }
+        }
+
+        #[allow(trivial_numeric_casts)]
+        impl crate::ops::Conversion<crate::unsigned::Ch64, $ty> {
+            /// Convert between types.
+            pub const fn convert(from: crate::unsigned::Ch64) -> $ty {
+                transfer!(@encode $ty, $p, $max, $transfer, from.into_inner())
+            }
+        }
+    };
+    (
+        @encode $ty: ident, $p: ty, $max: literal, $transfer: expr,
+        $linear: expr
+    ) => {{
+        // Flushes NaN to zero
+        let encoded = $transfer.encode($linear as f64);
+        let encoded = crate::unsigned::Ch64::new(encoded).into_inner();
+
+        $ty::new(crate::math::round(encoded.clamp(0.0, 1.0) * $max) as $p)
+    }};
+}
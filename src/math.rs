@@ -0,0 +1,296 @@
+//! `const` floating-point math
+//!
+//! `core` doesn't provide transcendental functions, so they're implemented
+//! here on [`f64`] (and are precise enough to correctly round into [`f32`]).
+
+#![allow(dead_code)]
+
+/// ln(2) upper bits (exact when multiplied by small integers)
+const LN_2_HI: f64 = f64::from_bits(0x3fe6_2e42_fee0_0000);
+/// ln(2) lower bits
+const LN_2_LO: f64 = f64::from_bits(0x3dea_39ef_3579_3c76);
+/// 2⁵²
+const TWO_52: f64 = 4_503_599_627_370_496.0;
+
+/// Round half away from zero.
+pub(crate) const fn round(x: f64) -> f64 {
+    let magnitude = x.abs();
+
+    // Already an integer (or NaN / infinity)
+    if magnitude >= TWO_52 || x.is_nan() {
+        return x;
+    }
+
+    let whole = magnitude as u64 as f64;
+    let whole = if magnitude - whole >= 0.5 {
+        whole + 1.0
+    } else {
+        whole
+    };
+
+    whole.copysign(x)
+}
+
+/// Round towards negative infinity.
+pub(crate) const fn floor(x: f64) -> f64 {
+    // Already an integer (or NaN / infinity)
+    if x.abs() >= TWO_52 || x.is_nan() {
+        return x;
+    }
+
+    let whole = x.abs() as u64 as f64;
+
+    if x.is_sign_negative() && whole != x.abs() {
+        -(whole + 1.0)
+    } else {
+        whole.copysign(x)
+    }
+}
+
+/// Multiply by 2ⁿ.
+const fn scale(mut x: f64, mut n: i32) -> f64 {
+    while n > 1023 {
+        x *= f64::from_bits(0x7fe0_0000_0000_0000);
+        n -= 1023;
+    }
+
+    while n < -1022 {
+        x *= f64::from_bits(0x0010_0000_0000_0000);
+        n += 1022;
+    }
+
+    x * f64::from_bits(((n + 1023) as u64) << 52)
+}
+
+/// Calculate eˣ.
+pub(crate) const fn exp(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    if x > 709.782_712_893_384 {
+        return f64::INFINITY;
+    }
+
+    if x < -745.133_219_101_941_2 {
+        return 0.0;
+    }
+
+    // Reduce range to |r| ≤ ln(2) / 2
+    let k = round(x * core::f64::consts::LOG2_E);
+    let r = (x - k * LN_2_HI) - k * LN_2_LO;
+    // Taylor series
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut i = 1;
+
+    while i < 20 {
+        term *= r / i as f64;
+        sum += term;
+        i += 1;
+    }
+
+    scale(sum, k as i32)
+}
+
+/// Calculate the natural logarithm of `x`.
+pub(crate) const fn ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+
+    if x.is_infinite() {
+        return x;
+    }
+
+    // Make subnormals normal
+    let (x, mut exponent) = if x.is_normal() {
+        (x, 0)
+    } else {
+        (x * f64::from_bits(0x4350_0000_0000_0000), -54)
+    };
+    let bits = x.to_bits();
+    // Split into mantissa (1 to 2) and exponent
+    let mut mantissa =
+        f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | (1023 << 52));
+
+    exponent += ((bits >> 52) & 0x7ff) as i32 - 1023;
+
+    // Center mantissa around 1 (√½ to √2)
+    if mantissa > core::f64::consts::SQRT_2 {
+        mantissa /= 2.0;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 atanh((m - 1) / (m + 1))
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let mut power = s;
+    let mut sum = 0.0;
+    let mut i = 1;
+
+    while i < 30 {
+        sum += power / i as f64;
+        power *= s2;
+        i += 2;
+    }
+
+    let exponent = exponent as f64;
+
+    (2.0 * sum + exponent * LN_2_LO) + exponent * LN_2_HI
+}
+
+/// Calculate the base 10 logarithm of `x`.
+pub(crate) const fn log10(x: f64) -> f64 {
+    ln(x) * core::f64::consts::LOG10_E
+}
+
+/// Raise non-negative `x` to the power of `y`.
+pub(crate) const fn pow(x: f64, y: f64) -> f64 {
+    if y == 0.0 {
+        return 1.0;
+    }
+
+    if x == 0.0 {
+        return if y > 0.0 { 0.0 } else { f64::INFINITY };
+    }
+
+    exp(y * ln(x))
+}
+
+/// Calculate the square root of `x`.
+pub(crate) const fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+
+    if x == 0.0 || x.is_infinite() {
+        return x;
+    }
+
+    // Halve the exponent for an initial guess, then refine (Newton's method)
+    let mut y = f64::from_bits((x.to_bits() >> 1) + (0x3ff << 51));
+    let mut i = 0;
+
+    while i < 10 {
+        y = 0.5 * (y + x / y);
+        i += 1;
+    }
+
+    y
+}
+
+/// Calculate the sine and cosine of `x` (radians).
+pub(crate) const fn sin_cos(x: f64) -> (f64, f64) {
+    /// π/2 upper bits (exact when multiplied by small integers)
+    const FRAC_PI_2_HI: f64 = f64::from_bits(0x3ff9_21fb_5440_0000);
+    /// π/2 lower bits
+    const FRAC_PI_2_LO: f64 = f64::from_bits(0x3dd0_b461_1a62_6331);
+
+    if !x.is_finite() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    // Reduce range to |r| ≤ π/4
+    let k = round(x * core::f64::consts::FRAC_2_PI);
+    let r = (x - k * FRAC_PI_2_HI) - k * FRAC_PI_2_LO;
+    let r2 = r * r;
+    // Taylor series
+    let (mut sin, mut cos) = (r, 1.0);
+    let (mut sin_term, mut cos_term) = (r, 1.0);
+    let mut i = 1;
+
+    while i < 12 {
+        let n = (2 * i) as f64;
+
+        cos_term *= -r2 / ((n - 1.0) * n);
+        sin_term *= -r2 / (n * (n + 1.0));
+        cos += cos_term;
+        sin += sin_term;
+        i += 1;
+    }
+
+    // Rotate into quadrant
+    match (k as i64) & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Calculate the sine of `x` (radians).
+pub(crate) const fn sin(x: f64) -> f64 {
+    sin_cos(x).0
+}
+
+/// Calculate the cosine of `x` (radians).
+pub(crate) const fn cos(x: f64) -> f64 {
+    sin_cos(x).1
+}
+
+/// Calculate the cube root of `x`.
+pub(crate) const fn cbrt(x: f64) -> f64 {
+    if x == 0.0 || !x.is_finite() {
+        return x;
+    }
+
+    let magnitude = x.abs();
+    let mut y = exp(ln(magnitude) / 3.0);
+
+    // Refine (Newton's method)
+    y -= (y * y * y - magnitude) / (3.0 * y * y);
+    y.copysign(x)
+}
+
+/// Calculate the arctangent of `x` (radians).
+pub(crate) const fn atan(x: f64) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+
+    // Reduce range to |x| ≤ 1
+    if x.abs() > 1.0 {
+        return core::f64::consts::FRAC_PI_2.copysign(x) - atan(1.0 / x);
+    }
+
+    // Halve angle twice to reduce range to |x| ≤ tan(π/16)
+    let x = x / (1.0 + sqrt(1.0 + x * x));
+    let x = x / (1.0 + sqrt(1.0 + x * x));
+    // Taylor series
+    let x2 = x * x;
+    let mut power = x;
+    let mut sum = 0.0;
+    let mut i = 1;
+
+    while i < 40 {
+        sum += power / i as f64;
+        power *= -x2;
+        i += 2;
+    }
+
+    4.0 * sum
+}
+
+/// Calculate the angle (radians) of the point (`x`, `y`) from -π to π.
+pub(crate) const fn atan2(y: f64, x: f64) -> f64 {
+    use core::f64::consts::{FRAC_PI_2, PI};
+
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    if x > 0.0 {
+        atan(y / x)
+    } else if x < 0.0 {
+        atan(y / x) + PI.copysign(y)
+    } else if y == 0.0 {
+        0.0
+    } else {
+        FRAC_PI_2.copysign(y)
+    }
+}
@@ -0,0 +1,310 @@
+//! Level metering
+//!
+//! Meters consume slices of signed channel values, and report levels as
+//! [`Ch32`] channel values (full scale is 1) or dBFS (decibels relative to
+//! full scale).  Integer channels are scaled so that the minimum value is
+//! exactly full scale.
+//!
+//!  - [`Peak`]: maximum absolute sample value
+//!  - [`Rms`]: root mean square over consecutive windows (without the AES17 +3
+//!    dB offset, so a full scale sine wave measures -3 dBFS)
+//!  - [`TruePeak`]: maximum absolute value after 4× oversampling (approximating
+//!    ITU-R BS.1770, reported in dBTP)
+//!
+//! The true-peak interpolation filter is a windowed sinc rather than the
+//! BS.1770 Annex 2 coefficient table, so readings can differ slightly from
+//! compliant meters.  Sine waves up to 0.4 of the sample rate (19.2 kHz at
+//! 48 kHz) read within 0.5 dB of their inter-sample peak.
+//!
+//! ```rust
+//! use p_chan::{
+//!     meter::{Peak, TruePeak},
+//!     signed::Ch32,
+//! };
+//!
+//! // Sine wave at a quarter of the sample rate, sampled between its peaks
+//! // (with an amplitude 3 dB above the sample peak)
+//! let wave: [Ch32; 64] = core::array::from_fn(|i| {
+//!     Ch32::new(if i % 4 < 2 { 0.5 } else { -0.5 })
+//! });
+//! let mut peak = Peak::<Ch32>::new();
+//! let mut true_peak = TruePeak::<Ch32>::new();
+//!
+//! peak.process(&wave);
+//! true_peak.process(&wave);
+//!
+//! assert_eq!(peak.level(), Ch32::new(0.5));
+//! assert!((true_peak.dbfs() - peak.dbfs() - 3.01).abs() < 0.2);
+//! ```
+
+use core::{f64::consts::PI, marker::PhantomData};
+
+use crate::{math, signed::Ch32};
+
+/// Number of true-peak filter taps per phase
+const TAPS: usize = 24;
+/// True-peak oversampling factor
+const OVERSAMPLING: usize = 4;
+/// True-peak polyphase interpolation filter coefficients
+const COEFFICIENTS: [[f64; TAPS]; OVERSAMPLING] = interpolation_filter();
+
+/// Design the true-peak interpolation filter (Blackman windowed-sinc with a
+/// cutoff at the original Nyquist frequency, and an odd length so that one
+/// phase passes input samples through unchanged).
+const fn interpolation_filter() -> [[f64; TAPS]; OVERSAMPLING] {
+    let len = TAPS * OVERSAMPLING - 1;
+    let center = (len - 1) as f64 / 2.0;
+    let mut coefficients = [[0.0; TAPS]; OVERSAMPLING];
+    let mut phase = 0;
+
+    while phase < OVERSAMPLING {
+        let mut sum = 0.0;
+        let mut tap = 0;
+
+        while tap < TAPS {
+            let n = tap * OVERSAMPLING + phase;
+
+            if n < len {
+                let x = (n as f64 - center) / OVERSAMPLING as f64;
+                let sinc = if x == 0.0 {
+                    1.0
+                } else {
+                    math::sin(PI * x) / (PI * x)
+                };
+                let angle = 2.0 * PI * n as f64 / (len - 1) as f64;
+                let window = 0.42 - 0.5 * math::cos(angle)
+                    + 0.08 * math::cos(2.0 * angle);
+
+                coefficients[phase][tap] = sinc * window;
+                sum += sinc * window;
+            }
+
+            tap += 1;
+        }
+
+        // Normalize each phase for unity gain
+        let mut tap = 0;
+
+        while tap < TAPS {
+            coefficients[phase][tap] /= sum;
+            tap += 1;
+        }
+
+        phase += 1;
+    }
+
+    coefficients
+}
+
+/// Convert a linear level to dBFS.
+const fn dbfs(level: f64) -> f32 {
+    (20.0 * math::log10(level)) as f32
+}
+
+/// Sample peak meter
+#[derive(Clone, Debug)]
+pub struct Peak<C> {
+    peak: f64,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for Peak<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> Peak<C> {
+    /// Create a new peak meter.
+    pub const fn new() -> Self {
+        Self {
+            peak: 0.0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Reset the peak.
+    pub const fn reset(&mut self) {
+        self.peak = 0.0;
+    }
+
+    /// Get the peak level.
+    pub const fn level(&self) -> Ch32 {
+        Ch32::new(self.peak as f32)
+    }
+
+    /// Get the peak level in dBFS.
+    pub const fn dbfs(&self) -> f32 {
+        dbfs(self.peak)
+    }
+
+    const fn push(&mut self, sample: f64) {
+        self.peak = self.peak.max(sample.abs());
+    }
+}
+
+/// RMS (root mean square) meter over consecutive windows
+#[derive(Clone, Debug)]
+pub struct Rms<C> {
+    /// Window length (in samples)
+    window: usize,
+    /// Sum of squares in the current window
+    sum: f64,
+    /// Number of samples in the current window
+    count: usize,
+    /// Mean square of the last complete window
+    mean: f64,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Rms<C> {
+    /// Create a new RMS meter over windows of `window` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub const fn new(window: usize) -> Self {
+        assert!(window != 0, "zero window");
+
+        Self {
+            window,
+            sum: 0.0,
+            count: 0,
+            mean: 0.0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Reset to silence.
+    pub const fn reset(&mut self) {
+        self.sum = 0.0;
+        self.count = 0;
+        self.mean = 0.0;
+    }
+
+    /// Get the RMS level of the last complete window.
+    pub const fn level(&self) -> Ch32 {
+        Ch32::new(math::sqrt(self.mean) as f32)
+    }
+
+    /// Get the RMS level of the last complete window in dBFS.
+    pub const fn dbfs(&self) -> f32 {
+        dbfs(math::sqrt(self.mean))
+    }
+
+    const fn push(&mut self, sample: f64) {
+        self.sum += sample * sample;
+        self.count += 1;
+
+        if self.count == self.window {
+            self.mean = self.sum / self.window as f64;
+            self.sum = 0.0;
+            self.count = 0;
+        }
+    }
+}
+
+/// True-peak meter (4× oversampling)
+#[derive(Clone, Debug)]
+pub struct TruePeak<C> {
+    history: [f64; TAPS],
+    /// Index of the newest sample in history
+    position: usize,
+    peak: f64,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Default for TruePeak<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C> TruePeak<C> {
+    /// Create a new true-peak meter.
+    pub const fn new() -> Self {
+        Self {
+            history: [0.0; TAPS],
+            position: 0,
+            peak: 0.0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Reset the peak (the filter history is kept, so that resetting
+    /// mid-stream doesn't measure a transient).
+    pub const fn reset(&mut self) {
+        self.peak = 0.0;
+    }
+
+    /// Get the true-peak level.
+    pub const fn level(&self) -> Ch32 {
+        Ch32::new(self.peak as f32)
+    }
+
+    /// Get the true-peak level in dBTP.
+    pub const fn dbfs(&self) -> f32 {
+        dbfs(self.peak)
+    }
+
+    const fn push(&mut self, sample: f64) {
+        self.position = (self.position + 1) % TAPS;
+        self.history[self.position] = sample;
+
+        let mut phase = 0;
+
+        while phase < OVERSAMPLING {
+            let mut sum = 0.0;
+            let mut tap = 0;
+
+            while tap < TAPS {
+                let index = (self.position + TAPS - tap) % TAPS;
+
+                sum += self.history[index] * COEFFICIENTS[phase][tap];
+                tap += 1;
+            }
+
+            self.peak = self.peak.max(sum.abs());
+            phase += 1;
+        }
+    }
+}
+
+macro_rules! meter {
+    ($($ch: ident: $scale: expr),* $(,)?) => {
+        $(
+            meter!(@meter Peak, $ch, $scale);
+            meter!(@meter Rms, $ch, $scale);
+            meter!(@meter TruePeak, $ch, $scale);
+        )*
+    };
+    (@meter $meter: ident, $ch: ident, $scale: expr) => {
+        #[allow(trivial_numeric_casts)]
+        impl $meter<$ch> {
+            /// Measure channel values.
+            pub const fn process(&mut self, chans: &[$ch]) {
+                let mut i = 0;
+
+                while i < chans.len() {
+                    self.push(chans[i].into_inner() as f64 / $scale);
+                    i += 1;
+                }
+            }
+        }
+    };
+}
+
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch64};
+
+    meter!(
+        Ch8: 128.0,
+        Ch10: 512.0,
+        Ch12: 2_048.0,
+        Ch16: 32_768.0,
+        Ch24: 8_388_608.0,
+        Ch32: 1.0,
+        Ch64: 1.0,
+    );
+}
@@ -0,0 +1,445 @@
+//! Upmixing and downmixing between channel layouts
+//!
+//! A [`Mix`] is a matrix of gains from each input channel to each output
+//! channel.  Mixes are applied to [`Frame`]s, and to interleaved or planar
+//! buffers with [`Mixing`].  Channels are accumulated in floating-point, and
+//! only clipped when converted back into integer channels.
+//!
+//! ```rust
+//! use p_chan::{
+//!     frame::{ChannelLayout, Frame},
+//!     mix::Mix,
+//!     ops::Mixing,
+//!     signed::Ch16,
+//! };
+//!
+//! let mix = Mix::standard(ChannelLayout::SURROUND_5_1, ChannelLayout::STEREO)
+//!     .unwrap();
+//! let mut frame = Frame([Ch16::new(0); 6]);
+//!
+//! // Center channel
+//! frame.0[2] = Ch16::new(10_000);
+//!
+//! let frame: Frame<Ch16, 2> = Mixing::<Ch16>::frame(&mix, frame);
+//!
+//! assert_eq!(frame, Frame([Ch16::new(7_071), Ch16::new(7_071)]));
+//! ```
+//!
+//! Standard mixes follow ITU-R BS.775 (centers and surrounds fold into the
+//! front at -3 dB), the low frequency effects channel is discarded when
+//! downmixing, and upmixing never synthesizes new channel content (except mono
+//! to stereo, which is split at -3 dB).
+
+use crate::frame::{ChannelLayout, Speaker};
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+use crate::{frame::Frame, math, ops::Mixing};
+
+/// Maximum number of input or output channels of a [`Mix`]
+pub const MAX_CHANNELS: usize = 32;
+
+/// -3 dB (1 / √2)
+const MINUS_3_DB: f32 = core::f32::consts::FRAC_1_SQRT_2;
+
+/// Channel mix matrix
+// Not `Copy`, so that the (4 KiB) matrix isn't duplicated implicitly
+#[allow(missing_copy_implementations)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mix {
+    /// Gains, indexed by output then input channel
+    gains: [[f32; MAX_CHANNELS]; MAX_CHANNELS],
+    inputs: usize,
+    outputs: usize,
+}
+
+impl Mix {
+    /// Create a mix from a matrix of gains (a row of input gains for each
+    /// output channel).
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than [`MAX_CHANNELS`] inputs or outputs.
+    pub const fn new<const I: usize, const O: usize>(
+        gains: [[f32; I]; O],
+    ) -> Self {
+        assert!(I <= MAX_CHANNELS && O <= MAX_CHANNELS, "too many channels");
+
+        let mut mix = Self::silent(I, O);
+        let mut output = 0;
+
+        while output < O {
+            let mut input = 0;
+
+            while input < I {
+                mix.gains[output][input] = gains[output][input];
+                input += 1;
+            }
+
+            output += 1;
+        }
+
+        mix
+    }
+
+    /// Create a mix that passes each channel through unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `channels` is more than [`MAX_CHANNELS`].
+    pub const fn identity(channels: usize) -> Self {
+        let mut mix = Self::silent(channels, channels);
+        let mut i = 0;
+
+        while i < channels {
+            mix.gains[i][i] = 1.0;
+            i += 1;
+        }
+
+        mix
+    }
+
+    /// Create a standard mix between speaker layouts.
+    ///
+    /// Returns `None` if either layout is ambisonic.
+    pub const fn standard(
+        from: ChannelLayout,
+        to: ChannelLayout,
+    ) -> Option<Self> {
+        let (ChannelLayout::Speakers(_), ChannelLayout::Speakers(_)) =
+            (from, to)
+        else {
+            return None;
+        };
+        let mut mix = Self::silent(from.channels(), to.channels());
+        let mut i = 0;
+
+        while i < Speaker::ALL.len() {
+            let speaker = Speaker::ALL[i];
+
+            if let Some(input) = from.index(speaker) {
+                mix.route(input, speaker, 1.0, to);
+            }
+
+            i += 1;
+        }
+
+        Some(mix)
+    }
+
+    /// Create a mix with all gains zero.
+    const fn silent(inputs: usize, outputs: usize) -> Self {
+        assert!(
+            inputs <= MAX_CHANNELS && outputs <= MAX_CHANNELS,
+            "too many channels",
+        );
+
+        Self {
+            gains: [[0.0; MAX_CHANNELS]; MAX_CHANNELS],
+            inputs,
+            outputs,
+        }
+    }
+
+    /// Route an input channel to a speaker position, falling back to nearby
+    /// positions that exist in the output layout.
+    const fn route(
+        &mut self,
+        input: usize,
+        speaker: Speaker,
+        gain: f32,
+        to: ChannelLayout,
+    ) {
+        use Speaker::*;
+
+        if let Some(output) = to.index(speaker) {
+            self.gains[output][input] += gain;
+            return;
+        }
+
+        let has_fronts = to.contains(FrontLeft) || to.contains(FrontRight);
+        let (first, second, fallback_gain) = match speaker {
+            FrontLeft | FrontRight if to.contains(FrontCenter) => {
+                (FrontCenter, None, MINUS_3_DB)
+            }
+            FrontCenter if has_fronts => {
+                (FrontLeft, Some(FrontRight), MINUS_3_DB)
+            }
+            FrontLeftOfCenter => (FrontLeft, None, 1.0),
+            FrontRightOfCenter => (FrontRight, None, 1.0),
+            BackLeft if to.contains(SideLeft) => (SideLeft, None, 1.0),
+            BackRight if to.contains(SideRight) => (SideRight, None, 1.0),
+            SideLeft if to.contains(BackLeft) => (BackLeft, None, 1.0),
+            SideRight if to.contains(BackRight) => (BackRight, None, 1.0),
+            BackLeft | SideLeft => (FrontLeft, None, MINUS_3_DB),
+            BackRight | SideRight => (FrontRight, None, MINUS_3_DB),
+            BackCenter if to.contains(BackLeft) || to.contains(SideLeft) => {
+                let (left, right) = if to.contains(BackLeft) {
+                    (BackLeft, BackRight)
+                } else {
+                    (SideLeft, SideRight)
+                };
+
+                (left, Some(right), MINUS_3_DB)
+            }
+            BackCenter => (FrontLeft, Some(FrontRight), 0.5),
+            TopCenter | TopFrontCenter => (FrontCenter, None, 1.0),
+            TopFrontLeft => (FrontLeft, None, 1.0),
+            TopFrontRight => (FrontRight, None, 1.0),
+            TopBackLeft => (BackLeft, None, 1.0),
+            TopBackRight => (BackRight, None, 1.0),
+            TopBackCenter => (BackCenter, None, 1.0),
+            // Discarded (low frequency effects, or no output to fold into)
+            _ => return,
+        };
+
+        self.route(input, first, gain * fallback_gain, to);
+
+        if let Some(second) = second {
+            self.route(input, second, gain * fallback_gain, to);
+        }
+    }
+
+    /// Get the number of input channels.
+    pub const fn inputs(&self) -> usize {
+        self.inputs
+    }
+
+    /// Get the number of output channels.
+    pub const fn outputs(&self) -> usize {
+        self.outputs
+    }
+
+    /// Get the gain from an input channel to an output channel.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` or `output` is out of bounds.
+    pub const fn gain(&self, output: usize, input: usize) -> f32 {
+        assert!(
+            output < self.outputs && input < self.inputs,
+            "out of bounds"
+        );
+
+        self.gains[output][input]
+    }
+
+    /// Scale down the gains so that no output can exceed full scale.
+    pub const fn normalized(mut self) -> Self {
+        let mut max = 1.0;
+        let mut output = 0;
+
+        while output < self.outputs {
+            let mut sum = 0.0;
+            let mut input = 0;
+
+            while input < self.inputs {
+                sum += self.gains[output][input].abs();
+                input += 1;
+            }
+
+            max = if sum > max { sum } else { max };
+            output += 1;
+        }
+
+        let mut output = 0;
+
+        while output < self.outputs {
+            let mut input = 0;
+
+            while input < self.inputs {
+                self.gains[output][input] /= max;
+                input += 1;
+            }
+
+            output += 1;
+        }
+
+        self
+    }
+
+    /// Mix one frame of floating-point channels.
+    #[cfg(any(feature = "signed", feature = "unsigned"))]
+    fn apply(
+        &self,
+        input: impl Fn(usize) -> f64,
+        mut output: impl FnMut(usize, f64),
+    ) {
+        for o in 0..self.outputs {
+            let mut sum = 0.0;
+
+            for (i, &gain) in self.gains[o][..self.inputs].iter().enumerate() {
+                if gain != 0.0 {
+                    sum += input(i) * gain as f64;
+                }
+            }
+
+            output(o, sum);
+        }
+    }
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! mixing {
+    (
+        $($kind: ident $ch: ident: $p: ty, $offset: expr, $scale: expr);*
+        $(;)?
+    ) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Mixing<$ch> {
+                /// Mix a frame.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `I` or `O` don't match the mix.
+                pub fn frame<const I: usize, const O: usize>(
+                    mix: &Mix,
+                    frame: Frame<$ch, I>,
+                ) -> Frame<$ch, O> {
+                    assert!(
+                        I == mix.inputs && O == mix.outputs,
+                        "channel count mismatch",
+                    );
+
+                    let mut out = Frame([$ch::MIN; O]);
+
+                    mix.apply(
+                        |i| mixing!(@into $offset, $scale, frame.0[i]),
+                        |o, value| {
+                            out.0[o] = mixing!(
+                                @from $kind $ch: $p, $offset, $scale, value
+                            );
+                        },
+                    );
+
+                    out
+                }
+
+                /// Mix an interleaved buffer.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the buffer lengths don't match the same number
+                /// of frames.
+                pub fn interleaved(
+                    mix: &Mix,
+                    input: &[$ch],
+                    output: &mut [$ch],
+                ) {
+                    let (inputs, outputs) = (mix.inputs, mix.outputs);
+                    let frames = input.len().checked_div(inputs).unwrap_or(0);
+
+                    assert!(
+                        input.len() == frames * inputs
+                            && output.len() == frames * outputs,
+                        "buffer length mismatch",
+                    );
+
+                    for frame in 0..frames {
+                        let input = &input[frame * inputs..][..inputs];
+                        let output = &mut output[frame * outputs..][..outputs];
+
+                        mix.apply(
+                            |i| mixing!(@into $offset, $scale, input[i]),
+                            |o, value| {
+                                output[o] = mixing!(
+                                    @from $kind $ch: $p, $offset, $scale, value
+                                );
+                            },
+                        );
+                    }
+                }
+
+                /// Mix a planar buffer (a slice for each channel).
+                ///
+                /// # Panics
+                ///
+                /// Panics if the number of slices doesn't match the mix, or
+                /// the slices have different lengths.
+                pub fn planar(
+                    mix: &Mix,
+                    input: &[&[$ch]],
+                    output: &mut [&mut [$ch]],
+                ) {
+                    assert!(
+                        input.len() == mix.inputs
+                            && output.len() == mix.outputs,
+                        "channel count mismatch",
+                    );
+
+                    let frames = input.first().map_or(0, |chans| chans.len());
+
+                    assert!(
+                        input.iter().all(|chans| chans.len() == frames)
+                            && output.iter().all(|chans| chans.len() == frames),
+                        "buffer length mismatch",
+                    );
+
+                    for frame in 0..frames {
+                        mix.apply(
+                            |i| mixing!(@into $offset, $scale, input[i][frame]),
+                            |o, value| {
+                                output[o][frame] = mixing!(
+                                    @from $kind $ch: $p, $offset, $scale, value
+                                );
+                            },
+                        );
+                    }
+                }
+            }
+        )*
+    };
+    (@into $offset: expr, $scale: expr, $chan: expr) => {
+        ($chan.into_inner() as f64 - $offset) / $scale
+    };
+    (
+        @from int $ch: ident: $p: ty, $offset: expr, $scale: expr,
+        $value: expr
+    ) => {{
+        let min = $ch::MIN.into_inner() as f64;
+        let max = $ch::MAX.into_inner() as f64;
+
+        // Round to nearest, and clip
+        $ch::new(math::round($value * $scale + $offset).clamp(min, max) as $p)
+    }};
+    (
+        @from float $ch: ident: $p: ty, $offset: expr, $scale: expr,
+        $value: expr
+    ) => {
+        $ch::new(($value * $scale + $offset) as $p)
+    };
+}
+
+// Integers are scaled so that zero (or the middle for unsigned) is silence,
+// and -1 maps to the minimum
+
+#[cfg(feature = "signed")]
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    mixing!(
+        int Ch8: i8, 0.0, 128.0;
+        int Ch10: i16, 0.0, 512.0;
+        int Ch12: i16, 0.0, 2_048.0;
+        int Ch16: i16, 0.0, 32_768.0;
+        int Ch24: i32, 0.0, 8_388_608.0;
+        float Ch32: f32, 0.0, 1.0;
+        float Ch64: f64, 0.0, 1.0;
+    );
+}
+
+#[cfg(feature = "unsigned")]
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    mixing!(
+        int Ch8: u8, 128.0, 128.0;
+        int Ch10: u16, 512.0, 512.0;
+        int Ch12: u16, 2_048.0, 2_048.0;
+        int Ch16: u16, 32_768.0, 32_768.0;
+        int Ch24: u32, 8_388_608.0, 8_388_608.0;
+        float Ch32: f32, 0.5, 0.5;
+        float Ch64: f64, 0.5, 0.5;
+    );
+}
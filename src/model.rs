@@ -0,0 +1,350 @@
+//! Cylindrical and perceptual color models
+//!
+//! [`Rgb`] pixels built from floating-point channels are converted to and from
+//! other color models with [`Conversion`]:
+//!
+//! ```rust
+//! use p_chan::{
+//!     ops::Conversion,
+//!     pixel::{Hsv, Rgb},
+//!     unsigned::Ch32,
+//! };
+//!
+//! let pixel = Rgb::new(Ch32::new(0.0), Ch32::new(0.5), Ch32::new(0.5));
+//! let hsv = Conversion::<Rgb<Ch32>, Hsv<Ch32>>::convert(pixel);
+//!
+//! assert_eq!(hsv, Hsv::new(Ch32::new(0.5), Ch32::new(1.0), Ch32::new(0.5)));
+//! ```
+//!
+//! [`Hsv`], [`Hsl`], and [`Hwb`] are calculated directly from the RGB values
+//! (usually non-linear, such as sRGB-encoded).  [`Lab`], [`Lch`], [`Oklab`],
+//! and [`Oklch`] expect linear RGB with BT.709 (sRGB) primaries and a D65
+//! white point.
+//!
+//! Hues are stored as a fraction of a turn from 0 to 1 (rather than degrees),
+//! and hue is 0 where chroma is 0.
+
+use core::f64::consts::TAU;
+
+use crate::{
+    math,
+    ops::Conversion,
+    pixel::{Hsl, Hsv, Hwb, Lab, Lch, Oklab, Oklch, Rgb},
+    unsigned::{Ch32, Ch64},
+};
+
+/// Linear BT.709 RGB to CIE XYZ
+const RGB_TO_XYZ: [[f64; 3]; 3] = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.1191920, 0.9503041],
+];
+/// CIE XYZ to linear BT.709 RGB
+const XYZ_TO_RGB: [[f64; 3]; 3] = invert(RGB_TO_XYZ);
+/// D65 white point (XYZ of RGB white, so that white has no chroma)
+const WHITE: [f64; 3] = mul(RGB_TO_XYZ, [1.0; 3]);
+/// CIELAB linear segment threshold (6/29)
+const DELTA: f64 = 6.0 / 29.0;
+/// Linear BT.709 RGB to Oklab LMS cone responses
+const RGB_TO_LMS: [[f64; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+/// Oklab LMS cone responses to linear BT.709 RGB
+const LMS_TO_RGB: [[f64; 3]; 3] = invert(RGB_TO_LMS);
+/// Non-linear LMS to Oklab
+const LMS_TO_LAB: [[f64; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+/// Oklab to non-linear LMS
+const LAB_TO_LMS: [[f64; 3]; 3] = invert(LMS_TO_LAB);
+
+/// Multiply a 3×3 matrix by a vector.
+const fn mul(matrix: [[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    let mut out = [0.0; 3];
+    let mut i = 0;
+
+    while i < out.len() {
+        let row = matrix[i];
+
+        out[i] = row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2];
+        i += 1;
+    }
+
+    out
+}
+
+/// Invert a 3×3 matrix (so that round trips are exact, rather than limited by
+/// the precision of published inverse matrices).
+const fn invert(m: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut out = [[0.0; 3]; 3];
+    let mut i = 0;
+
+    // Transposed cofactors
+    while i < 3 {
+        let mut j = 0;
+
+        while j < 3 {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+
+            out[i][j] = m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    let det = m[0][0] * out[0][0] + m[0][1] * out[1][0] + m[0][2] * out[2][0];
+    let mut i = 0;
+
+    while i < 3 {
+        let mut j = 0;
+
+        while j < 3 {
+            out[i][j] /= det;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    out
+}
+
+/// Wrap `x` into the range `0..modulus`.
+const fn wrap(x: f64, modulus: f64) -> f64 {
+    let x = x - modulus * math::floor(x / modulus);
+
+    // Rounding may land exactly on the modulus
+    if x >= modulus { 0.0 } else { x }
+}
+
+/// Get the hue (fraction of a turn), maximum, and minimum of `[r, g, b]`.
+const fn hue([r, g, b]: [f64; 3]) -> (f64, f64, f64) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+    let sextant = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        wrap((g - b) / chroma, 6.0)
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+
+    (sextant / 6.0, max, min)
+}
+
+/// Convert fully saturated hue (fraction of a turn) to `[r, g, b]`.
+const fn hue_to_rgb(hue: f64) -> [f64; 3] {
+    let mut out = [0.0; 3];
+    let mut i = 0;
+
+    while i < out.len() {
+        // Red, green, and blue peak a third of a turn apart
+        let k = wrap(hue * 6.0 + [5.0, 3.0, 1.0][i], 6.0);
+
+        out[i] = 1.0 - k.min(4.0 - k).clamp(0.0, 1.0);
+        i += 1;
+    }
+
+    out
+}
+
+const fn rgb_to_hsv(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, max, min) = hue(rgb);
+    let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+    [h, s, max]
+}
+
+const fn hsv_to_rgb([h, s, v]: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = hue_to_rgb(h);
+
+    [
+        v * (1.0 - s * (1.0 - r)),
+        v * (1.0 - s * (1.0 - g)),
+        v * (1.0 - s * (1.0 - b)),
+    ]
+}
+
+const fn rgb_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, max, min) = hue(rgb);
+    let l = (max + min) / 2.0;
+    let s = if l <= 0.0 || l >= 1.0 {
+        0.0
+    } else {
+        (max - l) / l.min(1.0 - l)
+    };
+
+    [h, s, l]
+}
+
+const fn hsl_to_rgb([h, s, l]: [f64; 3]) -> [f64; 3] {
+    let [r, g, b] = hue_to_rgb(h);
+    let chroma = s * l.min(1.0 - l) * 2.0;
+    let min = l - chroma / 2.0;
+
+    [min + chroma * r, min + chroma * g, min + chroma * b]
+}
+
+const fn rgb_to_hwb(rgb: [f64; 3]) -> [f64; 3] {
+    let (h, max, min) = hue(rgb);
+
+    [h, min, 1.0 - max]
+}
+
+const fn hwb_to_rgb([h, w, b]: [f64; 3]) -> [f64; 3] {
+    // Whiteness and blackness are scaled down to sum to at most 1
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+
+        return [gray; 3];
+    }
+
+    let [r, g, bl] = hue_to_rgb(h);
+    let chroma = 1.0 - w - b;
+
+    [w + chroma * r, w + chroma * g, w + chroma * bl]
+}
+
+const fn lab_f(t: f64) -> f64 {
+    if t > DELTA * DELTA * DELTA {
+        math::cbrt(t)
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+const fn lab_f_inverse(t: f64) -> f64 {
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+const fn rgb_to_lab(rgb: [f64; 3]) -> [f64; 3] {
+    let [x, y, z] = mul(RGB_TO_XYZ, rgb);
+    let fx = lab_f(x / WHITE[0]);
+    let fy = lab_f(y / WHITE[1]);
+    let fz = lab_f(z / WHITE[2]);
+
+    [1.16 * fy - 0.16, 5.0 * (fx - fy), 2.0 * (fy - fz)]
+}
+
+const fn lab_to_rgb([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let fy = (l + 0.16) / 1.16;
+    let fx = fy + a / 5.0;
+    let fz = fy - b / 2.0;
+    let xyz = [
+        lab_f_inverse(fx) * WHITE[0],
+        lab_f_inverse(fy) * WHITE[1],
+        lab_f_inverse(fz) * WHITE[2],
+    ];
+
+    mul(XYZ_TO_RGB, xyz)
+}
+
+const fn rgb_to_oklab(rgb: [f64; 3]) -> [f64; 3] {
+    let [l, m, s] = mul(RGB_TO_LMS, rgb);
+
+    mul(LMS_TO_LAB, [math::cbrt(l), math::cbrt(m), math::cbrt(s)])
+}
+
+const fn oklab_to_rgb(lab: [f64; 3]) -> [f64; 3] {
+    let [l, m, s] = mul(LAB_TO_LMS, lab);
+
+    mul(LMS_TO_RGB, [l * l * l, m * m * m, s * s * s])
+}
+
+/// Convert rectangular `[l, a, b]` to polar `[l, c, h]`.
+const fn to_polar([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let c = math::sqrt(a * a + b * b);
+    let h = if c == 0.0 {
+        0.0
+    } else {
+        wrap(math::atan2(b, a) / TAU, 1.0)
+    };
+
+    [l, c, h]
+}
+
+/// Convert polar `[l, c, h]` to rectangular `[l, a, b]`.
+const fn to_rectangular([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = math::sin_cos(h * TAU);
+
+    [l, c * cos, c * sin]
+}
+
+const fn rgb_to_lch(rgb: [f64; 3]) -> [f64; 3] {
+    to_polar(rgb_to_lab(rgb))
+}
+
+const fn lch_to_rgb(lch: [f64; 3]) -> [f64; 3] {
+    lab_to_rgb(to_rectangular(lch))
+}
+
+const fn rgb_to_oklch(rgb: [f64; 3]) -> [f64; 3] {
+    to_polar(rgb_to_oklab(rgb))
+}
+
+const fn oklch_to_rgb(lch: [f64; 3]) -> [f64; 3] {
+    oklab_to_rgb(to_rectangular(lch))
+}
+
+macro_rules! model {
+    ($($ch: ident: $p: ty),* $(,)?) => {
+        $(
+            model!(@pair $ch, $p, Rgb { r, g, b }, Hsv { h, s, v },
+                rgb_to_hsv, hsv_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Hsl { h, s, l },
+                rgb_to_hsl, hsl_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Hwb { h, w, b },
+                rgb_to_hwb, hwb_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Lab { l, a, b },
+                rgb_to_lab, lab_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Lch { l, c, h },
+                rgb_to_lch, lch_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Oklab { l, a, b },
+                rgb_to_oklab, oklab_to_rgb);
+            model!(@pair $ch, $p, Rgb { r, g, b }, Oklch { l, c, h },
+                rgb_to_oklch, oklch_to_rgb);
+            model!(@pair $ch, $p, Lab { l, a, b }, Lch { l, c, h },
+                to_polar, to_rectangular);
+            model!(@pair $ch, $p, Oklab { l, a, b }, Oklch { l, c, h },
+                to_polar, to_rectangular);
+        )*
+    };
+    (
+        @pair $ch: ident, $p: ty,
+        $a: ident { $($af: ident),* }, $b: ident { $($bf: ident),* },
+        $forward: ident, $backward: ident
+    ) => {
+        model!(@convert $ch, $p, $a { $($af),* }, $b { $($bf),* }, $forward);
+        model!(@convert $ch, $p, $b { $($bf),* }, $a { $($af),* }, $backward);
+    };
+    (
+        @convert $ch: ident, $p: ty,
+        $from: ident { $($ff: ident),* }, $to: ident { $($tf: ident),* },
+        $f: ident
+    ) => {
+        #[allow(trivial_numeric_casts)]
+        impl Conversion<$from<$ch>, $to<$ch>> {
+            /// Convert between color models.
+            pub const fn convert(from: $from<$ch>) -> $to<$ch> {
+                let [$($tf),*] = $f([$(from.$ff.into_inner() as f64),*]);
+
+                $to { $($tf: $ch::new($tf as $p)),* }
+            }
+        }
+    };
+}
+
+model!(Ch32: f32, Ch64: f64);
@@ -3,26 +3,74 @@
 //! This module provides wrapper structs for performing operations on channel
 //! values (which are `const` compatible).
 //!
+//! ## Amplification
+//!
+//! Multiply a channel value (or slice of channel values) by a gain (saturating
+//! for ints).
+//!
+//! ## Average
+//!
+//! Weighted average of channel values, with unsigned channel weights.
+//!
+//! ## Clipping
+//!
+//! Detect channel values outside of full scale, or soft clip them.
+//!
+//! ## Composition
+//!
+//! Composite a premultiplied source pixel over a premultiplied destination
+//! pixel.
+//!
 //! ## Conversion
 //!
 //! This is like [`From`] / [`Into`], but `const`.
 //!
+//! ## Decoding
+//!
+//! Decode a pixel with a color matrix into RGB.
+//!
 //! ## Difference
 //!
 //! Subtract a list of channel values from a channel value.
 //!
+//! ## Encoding
+//!
+//! Encode RGB into a pixel with a color matrix.
+//!
+//! ## Interpolation
+//!
+//! Linear interpolation between channel values, its inverse, and smoothstep
+//! (with an unsigned channel as the interpolation factor).
+//!
 //! ## Inversion
 //!
 //! Flip value between the minimum and maximum.
 //!
+//! ## Mixing
+//!
+//! Mix audio frames or buffers between channel layouts.
+//!
 //! ## Negation
 //!
 //! This is the same as inversion for signed floating-point channels.
 //!
+//! ## Panning
+//!
+//! Pan a mono channel value into a stereo frame, or adjust the balance of a
+//! stereo frame.
+//!
+//! ## Premultiplication
+//!
+//! Multiply (or divide) a pixel's color channels by its alpha channel.
+//!
 //! ## Product
 //!
 //! Multiply the channel values together (saturating for ints).
 //!
+//! ## Remapping
+//!
+//! Remap an integer channel value between full and limited range.
+//!
 //! ## Sum
 //!
 //! Add the channel values together (saturating for ints).
@@ -47,6 +95,82 @@ pub struct Product<T, const N: usize>(pub [T; N]);
 #[derive(Debug)]
 pub struct Conversion<T, U>(T, PhantomData<fn() -> U>);
 
+/// Constant amplification operation (`amplify`, `ramp`)
+///
+///  - `Amplification(_).amplify(gain)`
+///  - `Amplification(_).ramp(ramp, channels)`
+#[derive(Debug)]
+pub struct Amplification<T>(pub T);
+
+/// Constant weighted average operation (`mean`)
+///
+///  - `Average([], [weights]).mean()`
+#[derive(Debug)]
+pub struct Average<T, U, const N: usize>(pub [T; N], pub [U; N]);
+
+/// Constant interpolation operation (`lerp`, `inverse_lerp`, `smoothstep`)
+///
+///  - `Interpolation::<_, U>::lerp(a, b, t)`
+///  - `Interpolation::<_, U>::inverse_lerp(a, b, _)`
+///  - `Interpolation::<_, U>::smoothstep(a, b, _)`
+#[derive(Debug)]
+pub struct Interpolation<T, U>(PhantomData<fn() -> (T, U)>);
+
+/// Constant clipping operation (`is_clipped`, `count`, `soft_clip`)
+///
+///  - `Clipping(_).is_clipped()`
+///  - `Clipping(_).count()`
+///  - `Clipping(_).soft_clip(shape)`
+#[derive(Debug)]
+pub struct Clipping<T>(pub T);
+
+/// Constant compositing operation (`composite`, `blend`)
+///
+///  - `Composition(source, destination).composite(operator)`
+///  - `Composition(source, destination).blend(mode)`
+#[derive(Debug)]
+pub struct Composition<T>(pub T, pub T);
+
+/// Constant panning operation (`pan`)
+///
+///  - `Panning(_).pan(pan)`
+#[derive(Debug)]
+pub struct Panning<T>(pub T);
+
+/// Constant alpha premultiplication operation (`premultiply`,
+/// `unpremultiply`)
+///
+///  - `Premultiplication(_).premultiply()`
+///  - `Premultiplication(_).unpremultiply()`
+#[derive(Debug)]
+pub struct Premultiplication<T>(pub T);
+
+/// Constant color matrix decoding operation (`decode`)
+///
+///  - `Decoding::<_, U>::decode(_, matrix, range)`
+#[derive(Debug)]
+pub struct Decoding<T, U>(T, PhantomData<fn() -> U>);
+
+/// Constant color matrix encoding operation (`encode`)
+///
+///  - `Encoding::<_, U>::encode(_, matrix, range)`
+#[derive(Debug)]
+pub struct Encoding<T, U>(T, PhantomData<fn() -> U>);
+
+/// Constant range remapping operation (`remap`)
+///
+///  - `Remapping::<_, U>::remap(_, remap)`
+#[derive(Debug)]
+pub struct Remapping<T, U>(T, PhantomData<fn() -> U>);
+
+/// Channel mixing operation (`frame`, `interleaved`, `planar`)
+///
+///  - `Mixing::<T>::frame(mix, _)`
+///  - `Mixing::<T>::interleaved(mix, _, _)`
+///  - `Mixing::<T>::planar(mix, _, _)`
+#[derive(Debug)]
+pub struct Mixing<T>(PhantomData<fn() -> T>);
+
 /// Constant inversion operation (`invert`)
 ///
 ///  - `Inversion(_)::invert()`
@@ -127,14 +251,300 @@ macro_rules! float_channel {
     };
 }
 
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! convert_slice {
+    ($from: ty, $to: ty) => {
+        impl Conversion<$from, $to> {
+            /// Convert a slice between types.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `from` and `to` have different lengths.
+            pub const fn convert_slice(from: &[$from], to: &mut [$to]) {
+                assert!(from.len() == to.len(), "slice lengths differ");
+
+                let mut i = 0;
+
+                while i < from.len() {
+                    to[i] = Self::convert(from[i]);
+                    i += 1;
+                }
+            }
+        }
+    };
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! bfloat_channel {
+    ($($int:ident: $bits:literal),* $(,)?) => {
+        impl Conversion<Ch32, Bf16> {
+            /// Convert between types (rounding to nearest, ties to even).
+            pub const fn convert(from: Ch32) -> Bf16 {
+                Bf16::new(from.into_inner())
+            }
+        }
+
+        impl Conversion<Bf16, Ch32> {
+            /// Convert between types.
+            pub const fn convert(from: Bf16) -> Ch32 {
+                Ch32::new(from.into_inner())
+            }
+        }
+
+        impl<const BITS: u32> Conversion<ChN<BITS>, Bf16> {
+            /// Convert between types (rounding to nearest).
+            pub const fn convert(from: ChN<BITS>) -> Bf16 {
+                let value = Conversion::<ChN<BITS>, Ch64>::convert(from);
+
+                Bf16::new(value.into_inner() as f32)
+            }
+        }
+
+        convert_slice!(Ch32, Bf16);
+        convert_slice!(Bf16, Ch32);
+
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Conversion<$int, Bf16> {
+                /// Convert between types (rounding to nearest).
+                pub const fn convert(from: $int) -> Bf16 {
+                    let from = ChN::<$bits>::new(from.into_inner() as _);
+
+                    Conversion::<ChN<$bits>, Bf16>::convert(from)
+                }
+            }
+
+            convert_slice!($int, Bf16);
+        )*
+    };
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! chan_n {
+    ($($ty:ident: $bits:literal),* $(,)?) => {
+        $(
+            chan_n!(@float $ty, $bits, Ch32);
+            chan_n!(@float $ty, $bits, Ch64);
+            convert_slice!($ty, Ch32);
+            convert_slice!(Ch32, $ty);
+        )*
+    };
+    (@float $ty:ident, $bits:literal, $float:ident) => {
+        #[allow(trivial_numeric_casts)]
+        impl Conversion<$ty, $float> {
+            /// Convert between types.
+            pub const fn convert(from: $ty) -> $float {
+                let from = ChN::<$bits>::new(from.into_inner() as _);
+
+                Conversion::<ChN<$bits>, $float>::convert(from)
+            }
+        }
+
+        #[allow(trivial_numeric_casts)]
+        impl Conversion<$float, $ty> {
+            /// Convert between types (rounding to nearest).
+            pub const fn convert(from: $float) -> $ty {
+                let from = Conversion::<$float, ChN<$bits>>::convert(from);
+
+                $ty::new(from.into_inner() as _)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "unsigned")]
+macro_rules! interpolation {
+    ($u: tt, $($kind: ident $t: ty: $p: ty),* $(,)?) => {
+        $(interpolation!(@t $kind $t: $p, $u);)*
+    };
+    (@t $kind: ident $t: ty: $p: ty, [$($u_kind: ident $u: ty: $q: ty),*]) => {
+        $(interpolation!(@impl $kind $t: $p, $u_kind $u: $q);)*
+    };
+    (@impl $kind: ident $t: ty: $p: ty, $u_kind: ident $u: ty: $q: ty) => {
+        #[allow(trivial_numeric_casts)]
+        impl Interpolation<$t, $u> {
+            /// Linearly interpolate from `a` (where `t` is the minimum) to
+            /// `b` (where `t` is the maximum).
+            ///
+            /// Integers are correctly rounded (ties away from zero).
+            pub const fn lerp(a: $t, b: $t, t: $u) -> $t {
+                let (a, b) = (a.into_inner() as f64, b.into_inner() as f64);
+                // Clamped for out of range floats
+                let max = <$u>::MAX.into_inner() as f64;
+                let t = (t.into_inner() as f64).clamp(0.0, max);
+
+                interpolation!(@lerp $kind $t: $p, a, b, t, max)
+            }
+
+            /// Get the interpolation factor of `value` between `a` and `b`
+            /// (clamped, and the minimum if `a` equals `b`).
+            pub const fn inverse_lerp(a: $t, b: $t, value: $t) -> $u {
+                let t = interpolation!(@factor a, b, value);
+
+                interpolation!(@from $u_kind $u: $q, t)
+            }
+
+            /// Smooth Hermite interpolation factor of `value` between `a` and
+            /// `b` (clamped, and a step at `a` if `a` equals `b`).
+            pub const fn smoothstep(a: $t, b: $t, value: $t) -> $u {
+                let t = if a.into_inner() == b.into_inner() {
+                    if value.into_inner() < a.into_inner() { 0.0 } else { 1.0 }
+                } else {
+                    interpolation!(@factor a, b, value)
+                };
+
+                interpolation!(@from $u_kind $u: $q, t * t * (3.0 - 2.0 * t))
+            }
+        }
+
+        #[allow(trivial_numeric_casts)]
+        impl<const N: usize> Average<$t, $u, N> {
+            /// Calculate the weighted average.
+            ///
+            /// Integers are correctly rounded (ties away from zero).
+            ///
+            /// # Panics
+            ///
+            /// Panics if the weights add up to zero.
+            pub const fn mean(self) -> $t {
+                let (mut sum, mut total) = (0.0, 0.0);
+                let mut i = 0;
+
+                while i < N {
+                    let weight = self.1[i].into_inner() as f64;
+
+                    sum += self.0[i].into_inner() as f64 * weight;
+                    total += weight;
+                    i += 1;
+                }
+
+                assert!(total != 0.0, "zero total weight");
+
+                interpolation!(@round $kind $t: $p, sum / total)
+            }
+        }
+    };
+    (@factor $a: ident, $b: ident, $value: ident) => {{
+        let a = $a.into_inner() as f64;
+        let b = $b.into_inner() as f64;
+
+        if a == b {
+            0.0
+        } else {
+            (($value.into_inner() as f64 - a) / (b - a)).clamp(0.0, 1.0)
+        }
+    }};
+    (
+        @lerp int $t: ty: $p: ty,
+        $a: ident, $b: ident, $x: ident, $max: ident $(,)?
+    ) => {
+        // Exact before rounding, since the product fits in 53 bits
+        interpolation!(@round int $t: $p, $a + ($b - $a) * $x / $max)
+    };
+    (
+        @lerp float $t: ty: $p: ty,
+        $a: ident, $b: ident, $x: ident, $max: ident $(,)?
+    ) => {
+        <$t>::new((($a * ($max - $x) + $b * $x) / $max) as $p)
+    };
+    (@round int $t: ty: $p: ty, $value: expr) => {{
+        let min = <$t>::MIN.into_inner() as f64;
+        let max = <$t>::MAX.into_inner() as f64;
+
+        <$t>::new(math::round($value).clamp(min, max) as $p)
+    }};
+    (@round float $t: ty: $p: ty, $value: expr) => {
+        <$t>::new($value as $p)
+    };
+    (@from int $u: ty: $q: ty, $t: expr) => {
+        <$u>::new(math::round($t * <$u>::MAX.into_inner() as f64) as $q)
+    };
+    (@from float $u: ty: $q: ty, $t: expr) => {
+        <$u>::new($t as $q)
+    };
+}
+
 #[cfg(feature = "unsigned")]
 mod unsigned {
     use super::*;
-    use crate::unsigned::{Ch32, Ch64};
+    use crate::{
+        chan, math,
+        unsigned::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN},
+    };
 
     float_channel!(Ch32);
     float_channel!(Ch64);
 
+    chan_n!(Ch8: 8, Ch10: 10, Ch12: 12, Ch16: 16, Ch24: 24);
+
+    interpolation!(
+        [
+            int Ch8: u8,
+            int Ch10: u16,
+            int Ch12: u16,
+            int Ch16: u16,
+            int Ch24: u32,
+            float Ch32: f32,
+            float Ch64: f64
+        ],
+        int Ch8: u8,
+        int Ch10: u16,
+        int Ch12: u16,
+        int Ch16: u16,
+        int Ch24: u32,
+        float Ch32: f32,
+        float Ch64: f64,
+    );
+
+    impl<const FROM: u32, const TO: u32> Conversion<ChN<FROM>, ChN<TO>> {
+        /// Convert between bit depths (with bit replication when upscaling,
+        /// and truncation when downscaling).
+        pub const fn convert(from: ChN<FROM>) -> ChN<TO> {
+            let fraction = chan::uint_to_u32(from.into_inner(), FROM);
+
+            ChN::new(fraction >> (u32::BITS - TO))
+        }
+    }
+
+    impl<const BITS: u32> Conversion<ChN<BITS>, Ch32> {
+        /// Convert between types.
+        pub const fn convert(from: ChN<BITS>) -> Ch32 {
+            Ch32::new(
+                from.into_inner() as f32 / ChN::<BITS>::MAX.into_inner() as f32,
+            )
+        }
+    }
+
+    impl<const BITS: u32> Conversion<ChN<BITS>, Ch64> {
+        /// Convert between types.
+        pub const fn convert(from: ChN<BITS>) -> Ch64 {
+            Ch64::new(
+                from.into_inner() as f64 / ChN::<BITS>::MAX.into_inner() as f64,
+            )
+        }
+    }
+
+    impl<const BITS: u32> Conversion<Ch32, ChN<BITS>> {
+        /// Convert between types (rounding to nearest).
+        pub const fn convert(from: Ch32) -> ChN<BITS> {
+            Conversion::<Ch64, ChN<BITS>>::convert(Ch64::new(
+                from.into_inner() as f64
+            ))
+        }
+    }
+
+    impl<const BITS: u32> Conversion<Ch64, ChN<BITS>> {
+        /// Convert between types (rounding to nearest).
+        pub const fn convert(from: Ch64) -> ChN<BITS> {
+            let max = ChN::<BITS>::MAX.into_inner() as f64;
+            let value = from.into_inner().clamp(0.0, 1.0) * max;
+
+            ChN::new(math::round(value) as u32)
+        }
+    }
+
+    bfloat_channel!(Ch8: 8, Ch10: 10, Ch12: 12, Ch16: 16, Ch24: 24);
+
     impl Inversion<Ch32> {
         /// Invert the value.
         pub const fn invert(self) -> Ch32 {
@@ -167,7 +577,10 @@ mod unsigned {
 #[cfg(feature = "signed")]
 mod signed {
     use super::*;
-    use crate::signed::{Ch8, Ch16, Ch32, Ch64};
+    use crate::{
+        chan, math,
+        signed::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN},
+    };
 
     impl Conversion<Ch8, Ch16> {
         /// Convert between types.
@@ -182,6 +595,83 @@ mod signed {
     float_channel!(Ch32);
     float_channel!(Ch64);
 
+    bfloat_channel!(Ch8: 8, Ch10: 10, Ch12: 12, Ch16: 16, Ch24: 24);
+
+    chan_n!(Ch8: 8, Ch10: 10, Ch12: 12, Ch16: 16, Ch24: 24);
+
+    #[cfg(feature = "unsigned")]
+    interpolation!(
+        [
+            int crate::unsigned::Ch8: u8,
+            int crate::unsigned::Ch10: u16,
+            int crate::unsigned::Ch12: u16,
+            int crate::unsigned::Ch16: u16,
+            int crate::unsigned::Ch24: u32,
+            float crate::unsigned::Ch32: f32,
+            float crate::unsigned::Ch64: f64
+        ],
+        int Ch8: i8,
+        int Ch10: i16,
+        int Ch12: i16,
+        int Ch16: i16,
+        int Ch24: i32,
+        float Ch32: f32,
+        float Ch64: f64,
+    );
+
+    impl<const FROM: u32, const TO: u32> Conversion<ChN<FROM>, ChN<TO>> {
+        /// Convert between bit depths (with bit replication when upscaling,
+        /// and truncation when downscaling).
+        pub const fn convert(from: ChN<FROM>) -> ChN<TO> {
+            let fraction = chan::int_to_i32(from.into_inner(), FROM);
+
+            ChN::new(fraction >> (i32::BITS - TO))
+        }
+    }
+
+    // Offset so that minimum and maximum map to -1 and 1
+    impl<const BITS: u32> Conversion<ChN<BITS>, Ch32> {
+        /// Convert between types.
+        pub const fn convert(from: ChN<BITS>) -> Ch32 {
+            let value = Conversion::<ChN<BITS>, Ch64>::convert(from);
+
+            Ch32::new(value.into_inner() as f32)
+        }
+    }
+
+    impl<const BITS: u32> Conversion<ChN<BITS>, Ch64> {
+        /// Convert between types.
+        pub const fn convert(from: ChN<BITS>) -> Ch64 {
+            let value = 2.0 * from.into_inner() as f64 + 1.0;
+
+            Ch64::new(value / range::<BITS>())
+        }
+    }
+
+    impl<const BITS: u32> Conversion<Ch32, ChN<BITS>> {
+        /// Convert between types (rounding to nearest).
+        pub const fn convert(from: Ch32) -> ChN<BITS> {
+            Conversion::<Ch64, ChN<BITS>>::convert(Ch64::new(
+                from.into_inner() as f64
+            ))
+        }
+    }
+
+    impl<const BITS: u32> Conversion<Ch64, ChN<BITS>> {
+        /// Convert between types (rounding to nearest).
+        pub const fn convert(from: Ch64) -> ChN<BITS> {
+            let value = from.into_inner().clamp(-1.0, 1.0) * range::<BITS>();
+
+            ChN::new(math::floor(value / 2.0) as i32)
+        }
+    }
+
+    /// Difference between maximum and minimum
+    const fn range<const BITS: u32>() -> f64 {
+        ChN::<BITS>::MAX.into_inner() as f64
+            - ChN::<BITS>::MIN.into_inner() as f64
+    }
+
     impl Inversion<Ch32> {
         /// Invert the value.
         pub const fn invert(self) -> Ch32 {
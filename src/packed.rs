@@ -0,0 +1,297 @@
+//! Packed floating-point channels and pixels
+//!
+//! These are the unsigned mini-float and shared exponent formats used by GPU
+//! render targets and textures.  Encoded values can be normal, subnormal,
+//! zero, or infinity (NaN flushes to zero), and decode to normal `f32` values
+//! or zero.  Negative values flush to zero, since none of these formats have a
+//! sign bit.
+
+use crate::{conversions, math, ops::Conversion, unsigned::Ch32};
+
+/// Exponent bias for 5-bit exponents
+const BIAS: i32 = 15;
+/// Bit pattern for infinity (before shifting by mantissa bits)
+const INFINITY: u32 = 0b11111;
+
+macro_rules! minifloat {
+    ($ty: ident, $mantissa: literal, $docs: meta $(,)?) => {
+        #[$docs]
+        #[derive(
+            Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default,
+        )]
+        #[repr(transparent)]
+        pub struct $ty(u16);
+
+        impl core::fmt::Debug for $ty {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                core::fmt::Debug::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl core::fmt::Display for $ty {
+            fn fmt(
+                &self,
+                f: &mut core::fmt::Formatter<'_>,
+            ) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.to_f32(), f)
+            }
+        }
+
+        impl $ty {
+            /// Positive infinity
+            pub const INFINITY: Self = Self((INFINITY << $mantissa) as u16);
+            /// Maximum (finite) value
+            pub const MAX: Self = Self(((INFINITY << $mantissa) - 1) as u16);
+            /// Minimum value
+            pub const MIN: Self = Self(0);
+
+            /// Create a channel value from its bit representation.
+            ///
+            /// Bits beyond the width of the format are ignored, and NaN flushes
+            /// to zero.
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(normalize(bits as u32, $mantissa) as u16)
+            }
+
+            /// Get the bit representation of the channel value.
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            const fn to_f32(self) -> f32 {
+                decode(self.0 as u32, $mantissa)
+            }
+        }
+
+        impl Conversion<$ty, Ch32> {
+            /// Convert between types.
+            pub const fn convert(from: $ty) -> Ch32 {
+                Ch32::new(from.to_f32())
+            }
+        }
+
+        impl Conversion<Ch32, $ty> {
+            /// Convert between types.
+            pub const fn convert(from: Ch32) -> $ty {
+                $ty(encode(from.into_inner(), $mantissa) as u16)
+            }
+        }
+    };
+}
+
+minifloat!(
+    Ch11,
+    6,
+    doc =
+        "11-bit unsigned float (5-bit exponent, 6-bit mantissa) channel value",
+);
+
+minifloat!(
+    Ch10,
+    5,
+    doc =
+        "10-bit unsigned float (5-bit exponent, 5-bit mantissa) channel value",
+);
+
+/// Packed 11-bit red and green, 10-bit blue float pixel
+///
+/// Red is stored in the lowest bits, and blue in the highest bits.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+#[repr(transparent)]
+pub struct Rg11b10(u32);
+
+impl Rg11b10 {
+    /// Create a pixel from its bit representation (NaN flushes to zero).
+    pub const fn from_bits(bits: u32) -> Self {
+        let red = normalize(bits & 0x7ff, 6);
+        let green = normalize((bits >> 11) & 0x7ff, 6);
+        let blue = normalize(bits >> 22, 5);
+
+        Self(red | (green << 11) | (blue << 22))
+    }
+
+    /// Get the bit representation of the pixel.
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl Conversion<Rg11b10, [Ch32; 3]> {
+    /// Convert between types.
+    pub const fn convert(from: Rg11b10) -> [Ch32; 3] {
+        [
+            Ch32::new(decode(from.0 & 0x7ff, 6)),
+            Ch32::new(decode((from.0 >> 11) & 0x7ff, 6)),
+            Ch32::new(decode(from.0 >> 22, 5)),
+        ]
+    }
+}
+
+impl Conversion<[Ch32; 3], Rg11b10> {
+    /// Convert between types.
+    pub const fn convert(from: [Ch32; 3]) -> Rg11b10 {
+        let red = encode(from[0].into_inner(), 6);
+        let green = encode(from[1].into_inner(), 6);
+        let blue = encode(from[2].into_inner(), 5);
+
+        Rg11b10(red | (green << 11) | (blue << 22))
+    }
+}
+
+/// Packed 9-bit red, green, and blue mantissas with a shared 5-bit exponent
+///
+/// Red is stored in the lowest bits, and the exponent in the highest bits.
+/// This format can't represent infinity, so it is clamped to
+/// [`Rgb9e5::MAX_VALUE`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+#[repr(transparent)]
+pub struct Rgb9e5(u32);
+
+impl Rgb9e5 {
+    /// Largest value of any channel (65\_408)
+    pub const MAX_VALUE: f32 = 511.0 / 512.0 * 65_536.0;
+
+    /// Create a pixel from its bit representation.
+    pub const fn from_bits(bits: u32) -> Self {
+        Self(bits)
+    }
+
+    /// Get the bit representation of the pixel.
+    pub const fn to_bits(self) -> u32 {
+        self.0
+    }
+}
+
+impl Conversion<Rgb9e5, [Ch32; 3]> {
+    /// Convert between types.
+    pub const fn convert(from: Rgb9e5) -> [Ch32; 3] {
+        // 2^(exponent - bias - mantissa bits)
+        let scale = exp2((from.0 >> 27) as i32 - BIAS - 9);
+
+        [
+            Ch32::new((from.0 & 0x1ff) as f32 * scale),
+            Ch32::new(((from.0 >> 9) & 0x1ff) as f32 * scale),
+            Ch32::new(((from.0 >> 18) & 0x1ff) as f32 * scale),
+        ]
+    }
+}
+
+impl Conversion<[Ch32; 3], Rgb9e5> {
+    /// Convert between types.
+    pub const fn convert(from: [Ch32; 3]) -> Rgb9e5 {
+        let red = from[0].into_inner().clamp(0.0, Rgb9e5::MAX_VALUE);
+        let green = from[1].into_inner().clamp(0.0, Rgb9e5::MAX_VALUE);
+        let blue = from[2].into_inner().clamp(0.0, Rgb9e5::MAX_VALUE);
+        let max = red.max(green).max(blue);
+        // floor(log2(max)) (channels are normal or zero)
+        let log2 = ((max.to_bits() >> 23) as i32) - 127;
+        // Clamp to the smallest exponent
+        let log2 = if log2 < -BIAS - 1 { -BIAS - 1 } else { log2 };
+        let mut exponent = log2 + 1 + BIAS;
+
+        // Rounding may overflow the mantissa
+        if quantize(max, exponent) == 512 {
+            exponent += 1;
+        }
+
+        let red = quantize(red, exponent);
+        let green = quantize(green, exponent);
+        let blue = quantize(blue, exponent);
+
+        Rgb9e5(red | (green << 9) | (blue << 18) | ((exponent as u32) << 27))
+    }
+}
+
+/// Calculate 2ⁿ (for the normal range of exponents).
+const fn exp2(n: i32) -> f32 {
+    f32::from_bits(((n + 127) as u32) << 23)
+}
+
+/// Round a shared exponent mantissa to nearest.
+const fn quantize(chan: f32, exponent: i32) -> u32 {
+    let scaled = chan * exp2(BIAS + 9 - exponent);
+
+    math::floor(scaled as f64 + 0.5) as u32
+}
+
+/// Flush NaN to zero.
+const fn normalize(bits: u32, mantissa: u32) -> u32 {
+    let bits = bits & ((1 << (mantissa + 5)) - 1);
+    let fraction = bits & ((1 << mantissa) - 1);
+
+    if bits >> mantissa == INFINITY && fraction != 0 {
+        0
+    } else {
+        bits
+    }
+}
+
+/// Convert unsigned mini-float to [`f32`].
+const fn decode(bits: u32, mantissa: u32) -> f32 {
+    let exponent = bits >> mantissa;
+    let fraction = bits & ((1 << mantissa) - 1);
+
+    match exponent {
+        // Infinity or NaN (which is flushed to zero)
+        INFINITY if fraction == 0 => f32::INFINITY,
+        INFINITY => 0.0,
+        // Zero or subnormal (exactly representable as a normal `f32`)
+        0 => fraction as f32 * exp2(1 - BIAS - mantissa as i32),
+        // Normal, rebias exponent and extend mantissa
+        _ => f32::from_bits(
+            ((exponent + 127 - BIAS as u32) << 23)
+                | (fraction << (23 - mantissa)),
+        ),
+    }
+}
+
+/// Convert [`f32`] to unsigned mini-float (rounding to nearest, ties to even).
+const fn encode(float: f32, mantissa: u32) -> u32 {
+    let float = conversions::normalize_f32(float);
+
+    // Negative, zero, and NaN (flushed by normalization)
+    if float.is_sign_negative() || float == 0.0 {
+        return 0;
+    }
+
+    if float.is_infinite() {
+        return INFINITY << mantissa;
+    }
+
+    let bits = float.to_bits();
+    // Rebias exponent
+    let exponent = ((bits >> 23) as i32) - 127 + BIAS;
+    // Add inferred 1
+    let fraction = (bits & 0x7f_ffff) | (1 << 23);
+    // Subnormal results shift out more precision
+    let shift =
+        (23 - mantissa) as i32 + if exponent < 1 { 1 - exponent } else { 0 };
+
+    if shift > 24 {
+        return 0;
+    }
+
+    let shift = shift as u32;
+    let truncated = fraction >> shift;
+    let remainder = fraction & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    let round_up =
+        remainder > half || (remainder == half && truncated & 1 == 1);
+    // Inferred 1 carries into the exponent, as does rounding
+    let exponent = if exponent < 1 {
+        0
+    } else {
+        (exponent - 1) as u32
+    };
+    let bits = (exponent << mantissa) + truncated + round_up as u32;
+
+    // Overflow to infinity
+    if bits > INFINITY << mantissa {
+        INFINITY << mantissa
+    } else {
+        bits
+    }
+}
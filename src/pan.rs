@@ -0,0 +1,160 @@
+//! Stereo panning and balance
+//!
+//! A [`Pan`] is a pair of left and right gains, either for placing a mono
+//! channel value in a stereo frame with a [`PanLaw`] ([`Pan::new()`]), or for
+//! adjusting the balance of a stereo frame ([`Pan::balance()`]).  Pans are
+//! applied with [`Panning`], and can be `const`:
+//!
+//! ```rust
+//! use p_chan::{
+//!     frame::Frame,
+//!     ops::Panning,
+//!     pan::{Pan, PanLaw},
+//!     signed::Ch16,
+//! };
+//!
+//! const HARD_LEFT: Pan = Pan::new(-1.0, PanLaw::ConstantPower);
+//! const CENTER: Pan = Pan::new(0.0, PanLaw::Linear);
+//!
+//! let chan = Ch16::new(10_000);
+//!
+//! assert_eq!(Panning(chan).pan(HARD_LEFT), Frame([chan, Ch16::new(0)]));
+//! assert_eq!(Panning(chan).pan(CENTER), Frame([Ch16::new(5_000); 2]));
+//! ```
+//!
+//! [`Panning`]: crate::ops::Panning
+
+use core::f64::consts::FRAC_PI_4;
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+use crate::{
+    frame::Frame,
+    ops::{Amplification, Panning},
+};
+use crate::{gain::Gain, math};
+
+/// Pan law (attenuation of both channels at the center)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PanLaw {
+    /// Linear gains, -6 dB at the center (constant amplitude)
+    Linear,
+    /// Sine / cosine gains, -3 dB at the center (constant power)
+    ConstantPower,
+    /// Square root of the linear and constant power gains, -4.5 dB at the
+    /// center
+    Compromise,
+}
+
+impl PanLaw {
+    /// Get the gain of a side (1 at the side, and 0 at the other side).
+    const fn gain(self, position: f64) -> f64 {
+        let linear = (1.0 + position) / 2.0;
+        // Sine of each side (instead of cosine of one) is exactly 0 at the
+        // other side
+        let power = math::sin(FRAC_PI_4 * (1.0 + position));
+
+        match self {
+            Self::Linear => linear,
+            Self::ConstantPower => power,
+            Self::Compromise => math::sqrt(linear * power),
+        }
+    }
+}
+
+/// Left and right gains
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Pan {
+    left: Gain,
+    right: Gain,
+}
+
+impl Pan {
+    /// Center balance (no change to a stereo frame)
+    pub const CENTER: Self = Self::from_gains(Gain::UNITY, Gain::UNITY);
+
+    /// Create a pan from left and right gains.
+    pub const fn from_gains(left: Gain, right: Gain) -> Self {
+        Self { left, right }
+    }
+
+    /// Create a pan for a mono source at a position from -1 (left) to 1
+    /// (right) (clamped).
+    pub const fn new(position: f32, law: PanLaw) -> Self {
+        let position = position.clamp(-1.0, 1.0) as f64;
+
+        Self {
+            left: Gain::new(law.gain(-position) as f32),
+            right: Gain::new(law.gain(position) as f32),
+        }
+    }
+
+    /// Create a balance for a stereo source at a position from -1 (left) to
+    /// 1 (right) (clamped).
+    ///
+    /// The side away from the position is attenuated by the pan law
+    /// (relative to the center), and the other side is unchanged.
+    pub const fn balance(position: f32, law: PanLaw) -> Self {
+        let position = position.clamp(-1.0, 1.0) as f64;
+        let center = law.gain(0.0);
+
+        Self {
+            left: Gain::new((law.gain(-position) / center).min(1.0) as f32),
+            right: Gain::new((law.gain(position) / center).min(1.0) as f32),
+        }
+    }
+
+    /// Get the left gain.
+    pub const fn left(self) -> Gain {
+        self.left
+    }
+
+    /// Get the right gain.
+    pub const fn right(self) -> Gain {
+        self.right
+    }
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! panning {
+    ($($ch: ident),* $(,)?) => {
+        $(
+            impl Panning<$ch> {
+                /// Pan a mono channel value into a stereo frame.
+                pub const fn pan(self, pan: Pan) -> Frame<$ch, 2> {
+                    Frame([
+                        Amplification(self.0).amplify(pan.left),
+                        Amplification(self.0).amplify(pan.right),
+                    ])
+                }
+            }
+
+            impl Panning<Frame<$ch, 2>> {
+                /// Apply a pan (or balance) to a stereo frame.
+                pub const fn pan(self, pan: Pan) -> Frame<$ch, 2> {
+                    let [left, right] = self.0.0;
+
+                    Frame([
+                        Amplification(left).amplify(pan.left),
+                        Amplification(right).amplify(pan.right),
+                    ])
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "signed")]
+mod signed {
+    use super::*;
+    use crate::signed::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    panning!(Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64);
+}
+
+#[cfg(feature = "unsigned")]
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64};
+
+    panning!(Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64);
+}
@@ -0,0 +1,419 @@
+//! Pixel types built from channel values
+//!
+//! Pixels are `#[repr(C)]`, with channels laid out in the order of their
+//! fields.  Per-pixel conversion between channel formats is done by mapping a
+//! channel [`Conversion`] over each channel:
+//!
+//! ```rust
+//! use p_chan::{
+//!     ops::Conversion,
+//!     pixel::Rgba,
+//!     unsigned::{Ch8, Ch32},
+//! };
+//!
+//! let pixel = Rgba::new(Ch8::MAX, Ch8::new(51), Ch8::MIN, Ch8::MAX);
+//! let pixel = pixel.map(Conversion::<Ch8, Ch32>::convert);
+//!
+//! assert_eq!(pixel, Rgba::new(Ch32::MAX, Ch32::new(0.2), Ch32::MIN, Ch32::MAX));
+//! ```
+//!
+//! Layouts are converted with [`Conversion`], where missing alpha channels are
+//! filled in as opaque.
+
+use core::ops::{Add, Sub};
+
+use crate::ops::Conversion;
+
+macro_rules! pixel {
+    (
+        $ty: ident { $($field: ident: $doc: literal),* },
+        $n: literal,
+        $docs: meta $(,)?
+    ) => {
+        #[$docs]
+        #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Default)]
+        #[repr(C)]
+        pub struct $ty<C> {
+            $(
+                #[doc = $doc]
+                pub $field: C,
+            )*
+        }
+
+        impl<C> $ty<C> {
+            /// Create a new pixel.
+            pub const fn new($($field: C),*) -> Self {
+                Self { $($field),* }
+            }
+
+            /// Apply `f` to each channel (for example, to convert between
+            /// channel formats).
+            pub fn map<D>(self, mut f: impl FnMut(C) -> D) -> $ty<D> {
+                $ty { $($field: f(self.$field)),* }
+            }
+        }
+
+        impl<C> From<[C; $n]> for $ty<C> {
+            fn from([$($field),*]: [C; $n]) -> Self {
+                Self { $($field),* }
+            }
+        }
+
+        impl<C> From<$ty<C>> for [C; $n] {
+            fn from(pixel: $ty<C>) -> Self {
+                [$(pixel.$field),*]
+            }
+        }
+
+        impl<C: Add<Output = C>> Add for $ty<C> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<C: Sub<Output = C>> Sub for $ty<C> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+    };
+}
+
+pixel!(Gray { v: "Gray value" }, 1, doc = "Grayscale pixel");
+
+pixel!(
+    GrayAlpha {
+        v: "Gray value",
+        a: "Alpha (opacity)"
+    },
+    2,
+    doc = "Grayscale pixel with alpha",
+);
+
+pixel!(
+    Rgb {
+        r: "Red",
+        g: "Green",
+        b: "Blue"
+    },
+    3,
+    doc = "Red, green, and blue pixel",
+);
+
+pixel!(
+    Rgba {
+        r: "Red",
+        g: "Green",
+        b: "Blue",
+        a: "Alpha (opacity)"
+    },
+    4,
+    doc = "Red, green, and blue pixel with alpha",
+);
+
+pixel!(
+    Bgra {
+        b: "Blue",
+        g: "Green",
+        r: "Red",
+        a: "Alpha (opacity)"
+    },
+    4,
+    doc = "Blue, green, and red pixel with alpha",
+);
+
+pixel!(
+    Ycbcr {
+        y: "Luma",
+        cb: "Blue-difference chroma",
+        cr: "Red-difference chroma"
+    },
+    3,
+    doc = "Luma and chroma (Y′CbCr) pixel",
+);
+
+pixel!(
+    Hsv {
+        h: "Hue (fraction of a turn, starting from red)",
+        s: "Saturation",
+        v: "Value"
+    },
+    3,
+    doc = "Hue, saturation, and value pixel",
+);
+
+pixel!(
+    Hsl {
+        h: "Hue (fraction of a turn, starting from red)",
+        s: "Saturation",
+        l: "Lightness"
+    },
+    3,
+    doc = "Hue, saturation, and lightness pixel",
+);
+
+pixel!(
+    Hwb {
+        h: "Hue (fraction of a turn, starting from red)",
+        w: "Whiteness",
+        b: "Blackness"
+    },
+    3,
+    doc = "Hue, whiteness, and blackness pixel",
+);
+
+pixel!(
+    Lab {
+        l: "Lightness (L* divided by 100)",
+        a: "Green-red axis (a* divided by 100)",
+        b: "Blue-yellow axis (b* divided by 100)"
+    },
+    3,
+    doc = "CIELAB pixel",
+);
+
+pixel!(
+    Lch {
+        l: "Lightness (L* divided by 100)",
+        c: "Chroma (C* divided by 100)",
+        h: "Hue (fraction of a turn)"
+    },
+    3,
+    doc = "CIELCh pixel (polar CIELAB)",
+);
+
+pixel!(
+    Oklab {
+        l: "Perceived lightness",
+        a: "Green-red axis",
+        b: "Blue-yellow axis"
+    },
+    3,
+    doc = "Oklab pixel",
+);
+
+pixel!(
+    Oklch {
+        l: "Perceived lightness",
+        c: "Chroma",
+        h: "Hue (fraction of a turn)"
+    },
+    3,
+    doc = "Oklch pixel (polar Oklab)",
+);
+
+impl<C: Copy> Conversion<Gray<C>, Rgb<C>> {
+    /// Convert between types.
+    pub const fn convert(from: Gray<C>) -> Rgb<C> {
+        Rgb::new(from.v, from.v, from.v)
+    }
+}
+
+impl<C: Copy> Conversion<GrayAlpha<C>, Rgba<C>> {
+    /// Convert between types.
+    pub const fn convert(from: GrayAlpha<C>) -> Rgba<C> {
+        Rgba::new(from.v, from.v, from.v, from.a)
+    }
+}
+
+impl<C: Copy> Conversion<GrayAlpha<C>, Bgra<C>> {
+    /// Convert between types.
+    pub const fn convert(from: GrayAlpha<C>) -> Bgra<C> {
+        Bgra::new(from.v, from.v, from.v, from.a)
+    }
+}
+
+impl<C: Copy> Conversion<Rgba<C>, Rgb<C>> {
+    /// Convert between types (discarding alpha).
+    pub const fn convert(from: Rgba<C>) -> Rgb<C> {
+        Rgb::new(from.r, from.g, from.b)
+    }
+}
+
+impl<C: Copy> Conversion<Bgra<C>, Rgb<C>> {
+    /// Convert between types (discarding alpha).
+    pub const fn convert(from: Bgra<C>) -> Rgb<C> {
+        Rgb::new(from.r, from.g, from.b)
+    }
+}
+
+impl<C: Copy> Conversion<Rgba<C>, Bgra<C>> {
+    /// Convert between types.
+    pub const fn convert(from: Rgba<C>) -> Bgra<C> {
+        Bgra::new(from.b, from.g, from.r, from.a)
+    }
+}
+
+impl<C: Copy> Conversion<Bgra<C>, Rgba<C>> {
+    /// Convert between types.
+    pub const fn convert(from: Bgra<C>) -> Rgba<C> {
+        Rgba::new(from.r, from.g, from.b, from.a)
+    }
+}
+
+#[cfg(any(feature = "signed", feature = "unsigned"))]
+macro_rules! pixel_channel {
+    ($([$($generics: tt)*] $ch: ty),* $(,)?) => {
+        $(
+            impl<$($generics)*> Gray<$ch> {
+                /// Black (minimum)
+                pub const BLACK: Self = Self::new(<$ch>::MIN);
+                /// White (maximum)
+                pub const WHITE: Self = Self::new(<$ch>::MAX);
+
+                pixel_channel!(@methods v);
+            }
+
+            impl<$($generics)*> GrayAlpha<$ch> {
+                /// Opaque black
+                pub const BLACK: Self = Self::new(<$ch>::MIN, <$ch>::MAX);
+                /// Opaque white
+                pub const WHITE: Self = Self::new(<$ch>::MAX, <$ch>::MAX);
+                /// Fully transparent (black)
+                pub const TRANSPARENT: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN);
+
+                pixel_channel!(@methods v, a);
+            }
+
+            impl<$($generics)*> Rgb<$ch> {
+                /// Black (minimum)
+                pub const BLACK: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN, <$ch>::MIN);
+                /// White (maximum)
+                pub const WHITE: Self =
+                    Self::new(<$ch>::MAX, <$ch>::MAX, <$ch>::MAX);
+
+                pixel_channel!(@methods r, g, b);
+            }
+
+            impl<$($generics)*> Rgba<$ch> {
+                /// Opaque black
+                pub const BLACK: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN, <$ch>::MIN, <$ch>::MAX);
+                /// Opaque white
+                pub const WHITE: Self =
+                    Self::new(<$ch>::MAX, <$ch>::MAX, <$ch>::MAX, <$ch>::MAX);
+                /// Fully transparent (black)
+                pub const TRANSPARENT: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN, <$ch>::MIN, <$ch>::MIN);
+
+                pixel_channel!(@methods r, g, b, a);
+            }
+
+            impl<$($generics)*> Bgra<$ch> {
+                /// Opaque black
+                pub const BLACK: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN, <$ch>::MIN, <$ch>::MAX);
+                /// Opaque white
+                pub const WHITE: Self =
+                    Self::new(<$ch>::MAX, <$ch>::MAX, <$ch>::MAX, <$ch>::MAX);
+                /// Fully transparent (black)
+                pub const TRANSPARENT: Self =
+                    Self::new(<$ch>::MIN, <$ch>::MIN, <$ch>::MIN, <$ch>::MIN);
+
+                pixel_channel!(@methods b, g, r, a);
+            }
+
+            impl<$($generics)*> Conversion<Gray<$ch>, GrayAlpha<$ch>> {
+                /// Convert between types (opaque).
+                pub const fn convert(from: Gray<$ch>) -> GrayAlpha<$ch> {
+                    GrayAlpha::new(from.v, <$ch>::MAX)
+                }
+            }
+
+            impl<$($generics)*> Conversion<Gray<$ch>, Rgba<$ch>> {
+                /// Convert between types (opaque).
+                pub const fn convert(from: Gray<$ch>) -> Rgba<$ch> {
+                    Rgba::new(from.v, from.v, from.v, <$ch>::MAX)
+                }
+            }
+
+            impl<$($generics)*> Conversion<Gray<$ch>, Bgra<$ch>> {
+                /// Convert between types (opaque).
+                pub const fn convert(from: Gray<$ch>) -> Bgra<$ch> {
+                    Bgra::new(from.v, from.v, from.v, <$ch>::MAX)
+                }
+            }
+
+            impl<$($generics)*> Conversion<Rgb<$ch>, Rgba<$ch>> {
+                /// Convert between types (opaque).
+                pub const fn convert(from: Rgb<$ch>) -> Rgba<$ch> {
+                    Rgba::new(from.r, from.g, from.b, <$ch>::MAX)
+                }
+            }
+
+            impl<$($generics)*> Conversion<Rgb<$ch>, Bgra<$ch>> {
+                /// Convert between types (opaque).
+                pub const fn convert(from: Rgb<$ch>) -> Bgra<$ch> {
+                    Bgra::new(from.b, from.g, from.r, <$ch>::MAX)
+                }
+            }
+        )*
+    };
+    (@methods $($field: ident),*) => {
+        /// Calculates the middle point of `self` and `rhs` for each channel.
+        pub const fn midpoint(self, rhs: Self) -> Self {
+            Self { $($field: self.$field.midpoint(rhs.$field)),* }
+        }
+
+        /// Clamps each channel between the channels of `min` and `max`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if any channel of `min` is greater than the channel of `max`.
+        pub const fn clamp(self, min: Self, max: Self) -> Self {
+            Self { $($field: self.$field.clamp(min.$field, max.$field)),* }
+        }
+    };
+}
+
+#[cfg(feature = "unsigned")]
+mod unsigned {
+    use super::*;
+    use crate::unsigned::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN};
+
+    pixel_channel!(
+        [] Ch8,
+        [] Ch10,
+        [] Ch12,
+        [] Ch16,
+        [] Ch24,
+        [const BITS: u32] ChN<BITS>,
+        [] Bf16,
+        [] Ch32,
+        [] Ch64,
+    );
+}
+
+#[cfg(feature = "signed")]
+mod signed {
+    use super::*;
+    use crate::signed::{Bf16, Ch8, Ch10, Ch12, Ch16, Ch24, Ch32, Ch64, ChN};
+
+    pixel_channel!(
+        [] Ch8,
+        [] Ch10,
+        [] Ch12,
+        [] Ch16,
+        [] Ch24,
+        [const BITS: u32] ChN<BITS>,
+        [] Bf16,
+        [] Ch32,
+        [] Ch64,
+    );
+}
+
+#[cfg(feature = "srgb")]
+mod srgb {
+    use super::*;
+    use crate::srgb::{Ch8, Ch16};
+
+    pixel_channel!([] Ch8, [] Ch16);
+}
@@ -0,0 +1,810 @@
+//! Planar and chroma subsampled Y′CbCr image layouts
+//!
+//! [`Yuv`] and [`YuvMut`] borrow the planes of a decoded video frame, and
+//! convert to and from interleaved [`Rgb`] and [`Rgba`] images (borrowed as
+//! [`Plane`]s of pixels).  Common layouts are:
+//!
+//!  - I444, I422, and I420: three planes with [`Chroma::Planar`]
+//!  - NV12: two planes with [`Chroma::SemiPlanar`] and [`Subsampling::Yuv420`]
+//!  - P010: NV12 with [`P010`] samples (10-bit code values stored in the most
+//!    significant bits of 16), converting to and from [`Ch10`] pixels
+//!
+//! ```rust
+//! use p_chan::{
+//!     pixel::Rgb,
+//!     planar::{Chroma, Filter, Plane, PlaneMut, Siting, Subsampling, Yuv},
+//!     unsigned::Ch8,
+//!     ycbcr::{Matrix, Range},
+//! };
+//!
+//! // 2×2 I420 image
+//! let y = [Ch8::new(235); 4];
+//! let (cb, cr) = ([Ch8::new(128)], [Ch8::new(128)]);
+//! let yuv = Yuv::new(
+//!     Plane::new(&y, 2, 2, 2),
+//!     Chroma::Planar {
+//!         cb: Plane::new(&cb, 1, 1, 1),
+//!         cr: Plane::new(&cr, 1, 1, 1),
+//!     },
+//!     Subsampling::Yuv420,
+//!     Siting::Left,
+//! );
+//! let mut rgb = [Rgb::<Ch8>::BLACK; 4];
+//!
+//! yuv.unpack_rgb(
+//!     &mut PlaneMut::new(&mut rgb, 2, 2, 2),
+//!     Matrix::Bt709,
+//!     Range::Limited,
+//!     Filter::Linear,
+//! );
+//!
+//! assert_eq!(rgb, [Rgb::<Ch8>::WHITE; 4]);
+//! ```
+
+use crate::{
+    math,
+    ops::{Conversion, Decoding, Encoding},
+    pixel::{Rgb, Rgba, Ycbcr},
+    unsigned::{Ch8, Ch10, Ch12, Ch16, Ch32, Ch64},
+    ycbcr::{Matrix, Range},
+};
+
+/// Chroma subsampling
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Subsampling {
+    /// No subsampling (4:4:4)
+    Yuv444,
+    /// Half horizontal resolution (4:2:2)
+    Yuv422,
+    /// Half horizontal and vertical resolution (4:2:0)
+    Yuv420,
+}
+
+impl Subsampling {
+    /// Get the horizontal and vertical subsampling factors.
+    const fn factors(self) -> (usize, usize) {
+        match self {
+            Self::Yuv444 => (1, 1),
+            Self::Yuv422 => (2, 1),
+            Self::Yuv420 => (2, 2),
+        }
+    }
+
+    /// Get the chroma plane width and height for a luma width and height.
+    pub const fn chroma_size(
+        self,
+        width: usize,
+        height: usize,
+    ) -> (usize, usize) {
+        let (x, y) = self.factors();
+
+        (width.div_ceil(x), height.div_ceil(y))
+    }
+}
+
+/// Chroma sample position (siting), relative to luma samples
+///
+/// Only applies to subsampled directions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Siting {
+    /// Centered between luma samples (JPEG, MPEG-1)
+    Center,
+    /// Co-sited horizontally with the left luma sample, and centered
+    /// vertically (MPEG-2, H.264, and HEVC default)
+    Left,
+    /// Co-sited with the top-left luma sample (BT.2020 and BT.2100)
+    TopLeft,
+}
+
+impl Siting {
+    /// Get the horizontal and vertical chroma offset (in luma samples).
+    const fn offsets(self, subsampling: Subsampling) -> (f64, f64) {
+        let (x, y) = match self {
+            Self::Center => (0.5, 0.5),
+            Self::Left => (0.0, 0.5),
+            Self::TopLeft => (0.0, 0.0),
+        };
+        let (sx, sy) = subsampling.factors();
+
+        (if sx == 1 { 0.0 } else { x }, if sy == 1 { 0.0 } else { y })
+    }
+}
+
+/// Chroma resampling filter
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Filter {
+    /// Nearest sample (duplicate when upsampling, and drop when downsampling)
+    Nearest,
+    /// Linear interpolation when upsampling, and a triangle (tent) filter when
+    /// downsampling
+    Linear,
+}
+
+/// P010 sample (10-bit code value in the most significant bits of 16)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Default)]
+#[repr(transparent)]
+pub struct P010(u16);
+
+impl P010 {
+    /// Create a sample from its bit representation (the low 6 bits are
+    /// ignored).
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits & !0x3f)
+    }
+
+    /// Get the bit representation of the sample.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+}
+
+impl Conversion<P010, Ch10> {
+    /// Convert between types.
+    pub const fn convert(from: P010) -> Ch10 {
+        Ch10::new(from.0 >> 6)
+    }
+}
+
+impl Conversion<Ch10, P010> {
+    /// Convert between types.
+    pub const fn convert(from: Ch10) -> P010 {
+        P010(from.into_inner() << 6)
+    }
+}
+
+/// Borrowed image plane (rows of `width` values, `stride` values apart)
+#[derive(Copy, Clone, Debug)]
+pub struct Plane<'a, T> {
+    data: &'a [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a, T: Copy> Plane<'a, T> {
+    /// Create a new plane view over `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is less than `width`, or `data` is too short.
+    pub const fn new(
+        data: &'a [T],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Self {
+        assert!(stride >= width, "stride less than width");
+        assert!(data.len() >= len(width, height, stride), "plane too short");
+
+        Self {
+            data,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Get the width.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height.
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the stride (distance between the start of each row).
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the value at (`x`, `y`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if (`x`, `y`) is out of bounds.
+    pub const fn get(&self, x: usize, y: usize) -> T {
+        assert!(x < self.width && y < self.height, "out of bounds");
+
+        self.data[y * self.stride + x]
+    }
+}
+
+/// Mutably borrowed image plane (rows of `width` values, `stride` values
+/// apart)
+#[derive(Debug)]
+pub struct PlaneMut<'a, T> {
+    data: &'a mut [T],
+    width: usize,
+    height: usize,
+    stride: usize,
+}
+
+impl<'a, T: Copy> PlaneMut<'a, T> {
+    /// Create a new mutable plane view over `data`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is less than `width`, or `data` is too short.
+    pub const fn new(
+        data: &'a mut [T],
+        width: usize,
+        height: usize,
+        stride: usize,
+    ) -> Self {
+        assert!(stride >= width, "stride less than width");
+        assert!(data.len() >= len(width, height, stride), "plane too short");
+
+        Self {
+            data,
+            width,
+            height,
+            stride,
+        }
+    }
+
+    /// Get the width.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the height.
+    pub const fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Get the stride (distance between the start of each row).
+    pub const fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Get the value at (`x`, `y`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if (`x`, `y`) is out of bounds.
+    pub const fn get(&self, x: usize, y: usize) -> T {
+        assert!(x < self.width && y < self.height, "out of bounds");
+
+        self.data[y * self.stride + x]
+    }
+
+    /// Set the value at (`x`, `y`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if (`x`, `y`) is out of bounds.
+    pub const fn set(&mut self, x: usize, y: usize, value: T) {
+        assert!(x < self.width && y < self.height, "out of bounds");
+
+        self.data[y * self.stride + x] = value;
+    }
+
+    /// Reborrow as an immutable plane.
+    pub const fn as_plane(&self) -> Plane<'_, T> {
+        Plane {
+            data: self.data,
+            width: self.width,
+            height: self.height,
+            stride: self.stride,
+        }
+    }
+}
+
+/// Get the minimum slice length of a plane.
+const fn len(width: usize, height: usize, stride: usize) -> usize {
+    if height == 0 {
+        0
+    } else {
+        (height - 1) * stride + width
+    }
+}
+
+/// Chroma plane layout
+#[derive(Copy, Clone, Debug)]
+pub enum Chroma<P> {
+    /// Separate blue-difference and red-difference planes (for example, I420)
+    Planar {
+        /// Blue-difference plane
+        cb: P,
+        /// Red-difference plane
+        cr: P,
+    },
+    /// Single plane of interleaved blue-difference and red-difference values
+    /// (for example, NV12), twice the chroma width
+    SemiPlanar(P),
+}
+
+impl<P> Chroma<P> {
+    /// Get the plane and index within it of each chroma component.
+    const fn planes(&self) -> [(&P, usize, usize); 2] {
+        match self {
+            Self::Planar { cb, cr } => [(cb, 1, 0), (cr, 1, 0)],
+            Self::SemiPlanar(cbcr) => [(cbcr, 2, 0), (cbcr, 2, 1)],
+        }
+    }
+}
+
+/// Borrowed planar Y′CbCr image
+#[derive(Copy, Clone, Debug)]
+pub struct Yuv<'a, C> {
+    luma: Plane<'a, C>,
+    chroma: Chroma<Plane<'a, C>>,
+    subsampling: Subsampling,
+    siting: Siting,
+}
+
+impl<'a, C: Copy> Yuv<'a, C> {
+    /// Create a new planar image view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the chroma planes doesn't match the luma plane
+    /// and subsampling.
+    pub const fn new(
+        luma: Plane<'a, C>,
+        chroma: Chroma<Plane<'a, C>>,
+        subsampling: Subsampling,
+        siting: Siting,
+    ) -> Self {
+        let (width, height) = subsampling.chroma_size(luma.width, luma.height);
+        let [(cb, step, _), (cr, _, _)] = chroma.planes();
+
+        assert!(
+            cb.width == width * step
+                && cr.width == width * step
+                && cb.height == height
+                && cr.height == height,
+            "chroma size mismatch",
+        );
+
+        Self {
+            luma,
+            chroma,
+            subsampling,
+            siting,
+        }
+    }
+
+    /// Get the luma plane.
+    pub const fn luma(&self) -> Plane<'a, C> {
+        self.luma
+    }
+
+    /// Get the chroma planes.
+    pub const fn chroma(&self) -> Chroma<Plane<'a, C>> {
+        self.chroma
+    }
+}
+
+/// Mutably borrowed planar Y′CbCr image
+#[derive(Debug)]
+pub struct YuvMut<'a, C> {
+    luma: PlaneMut<'a, C>,
+    chroma: Chroma<PlaneMut<'a, C>>,
+    subsampling: Subsampling,
+    siting: Siting,
+}
+
+impl<'a, C: Copy> YuvMut<'a, C> {
+    /// Create a new mutable planar image view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the chroma planes doesn't match the luma plane
+    /// and subsampling.
+    pub const fn new(
+        luma: PlaneMut<'a, C>,
+        chroma: Chroma<PlaneMut<'a, C>>,
+        subsampling: Subsampling,
+        siting: Siting,
+    ) -> Self {
+        let (width, height) = subsampling.chroma_size(luma.width, luma.height);
+        let [(cb, step, _), (cr, _, _)] = chroma.planes();
+
+        assert!(
+            cb.width == width * step
+                && cr.width == width * step
+                && cb.height == height
+                && cr.height == height,
+            "chroma size mismatch",
+        );
+
+        Self {
+            luma,
+            chroma,
+            subsampling,
+            siting,
+        }
+    }
+
+    /// Reborrow as an immutable planar image.
+    pub const fn as_yuv(&self) -> Yuv<'_, C> {
+        Yuv {
+            luma: self.luma.as_plane(),
+            chroma: match &self.chroma {
+                Chroma::Planar { cb, cr } => Chroma::Planar {
+                    cb: cb.as_plane(),
+                    cr: cr.as_plane(),
+                },
+                Chroma::SemiPlanar(cbcr) => Chroma::SemiPlanar(cbcr.as_plane()),
+            },
+            subsampling: self.subsampling,
+            siting: self.siting,
+        }
+    }
+}
+
+/// Get the (up to 4) taps of a triangle filter along one axis, centered at
+/// `center` with a radius of `factor` samples.
+fn taps(center: f64, factor: usize, len: usize) -> [(usize, f64); 4] {
+    let radius = factor as f64;
+    let first = math::floor(center - radius) + 1.0;
+    let mut taps = [(0, 0.0); 4];
+
+    for (i, tap) in taps.iter_mut().enumerate() {
+        let position = first + i as f64;
+        let weight = 1.0 - (position - center).abs() / radius;
+
+        if position >= 0.0 && position < len as f64 && weight > 0.0 {
+            *tap = (position as usize, weight);
+        }
+    }
+
+    taps
+}
+
+/// Sample a chroma component (0 for blue, 1 for red) at a luma position.
+fn upsample<C: Copy>(
+    yuv: &Yuv<'_, C>,
+    component: usize,
+    (x, y): (usize, usize),
+    filter: Filter,
+    value: impl Fn(C) -> f64,
+) -> f64 {
+    let (plane, step, index) = yuv.chroma.planes()[component];
+    let (sx, sy) = yuv.subsampling.factors();
+    let (ox, oy) = yuv.siting.offsets(yuv.subsampling);
+    let (width, height) = (plane.width / step, plane.height);
+    let cx = (x as f64 - ox) / sx as f64;
+    let cy = (y as f64 - oy) / sy as f64;
+    let get = |i: f64, j: f64| {
+        let i = (i.max(0.0) as usize).min(width - 1);
+        let j = (j.max(0.0) as usize).min(height - 1);
+
+        value(plane.get(i * step + index, j))
+    };
+
+    match filter {
+        // Round half down (so co-sited chroma is duplicated to the right)
+        Filter::Nearest => get(-math::floor(0.5 - cx), -math::floor(0.5 - cy)),
+        Filter::Linear => {
+            let (i, j) = (math::floor(cx), math::floor(cy));
+            let (tx, ty) = (cx - i, cy - j);
+            let top = get(i, j) * (1.0 - tx) + get(i + 1.0, j) * tx;
+            let bottom =
+                get(i, j + 1.0) * (1.0 - tx) + get(i + 1.0, j + 1.0) * tx;
+
+            top * (1.0 - ty) + bottom * ty
+        }
+    }
+}
+
+/// Filter `[cb, cr]` at luma resolution down to a chroma position.
+fn downsample<P: Copy>(
+    from: &Plane<'_, P>,
+    (i, j): (usize, usize),
+    (subsampling, siting): (Subsampling, Siting),
+    filter: Filter,
+    value: impl Fn(P) -> [f64; 2],
+) -> [f64; 2] {
+    let (sx, sy) = subsampling.factors();
+    let (ox, oy) = siting.offsets(subsampling);
+    let cx = (i * sx) as f64 + ox;
+    let cy = (j * sy) as f64 + oy;
+
+    if filter == Filter::Nearest {
+        let x = (cx as usize).min(from.width - 1);
+        let y = (cy as usize).min(from.height - 1);
+
+        return value(from.get(x, y));
+    }
+
+    let mut sum = [0.0; 2];
+    let mut total = 0.0;
+
+    for (y, wy) in taps(cy, sy, from.height) {
+        for (x, wx) in taps(cx, sx, from.width) {
+            let weight = wx * wy;
+
+            if weight > 0.0 {
+                let [cb, cr] = value(from.get(x, y));
+
+                sum[0] += cb * weight;
+                sum[1] += cr * weight;
+                total += weight;
+            }
+        }
+    }
+
+    [sum[0] / total, sum[1] / total]
+}
+
+/// Convert planar to interleaved.
+fn unpack<C: Copy, P: Copy>(
+    from: &Yuv<'_, C>,
+    to: &mut PlaneMut<'_, P>,
+    filter: Filter,
+    (value, quantize): (impl Fn(C) -> f64, impl Fn(f64) -> C),
+    decode: impl Fn(Ycbcr<C>) -> P,
+) {
+    assert!(
+        to.width == from.luma.width && to.height == from.luma.height,
+        "image size mismatch",
+    );
+
+    for y in 0..to.height {
+        for x in 0..to.width {
+            let cb = upsample(from, 0, (x, y), filter, &value);
+            let cr = upsample(from, 1, (x, y), filter, &value);
+            let ycbcr =
+                Ycbcr::new(from.luma.get(x, y), quantize(cb), quantize(cr));
+
+            to.set(x, y, decode(ycbcr));
+        }
+    }
+}
+
+/// Convert interleaved to planar.
+fn pack<C: Copy, P: Copy>(
+    to: &mut YuvMut<'_, C>,
+    from: &Plane<'_, P>,
+    filter: Filter,
+    (value, quantize): (impl Fn(C) -> f64, impl Fn(f64) -> C),
+    encode: impl Fn(P) -> Ycbcr<C>,
+) {
+    assert!(
+        to.luma.width == from.width && to.luma.height == from.height,
+        "image size mismatch",
+    );
+
+    for y in 0..from.height {
+        for x in 0..from.width {
+            to.luma.set(x, y, encode(from.get(x, y)).y);
+        }
+    }
+
+    let (width, height) = to.subsampling.chroma_size(from.width, from.height);
+    let sampling = (to.subsampling, to.siting);
+
+    for j in 0..height {
+        for i in 0..width {
+            let [cb, cr] = downsample(from, (i, j), sampling, filter, |p| {
+                let ycbcr = encode(p);
+
+                [value(ycbcr.cb), value(ycbcr.cr)]
+            });
+            let (cb, cr) = (quantize(cb), quantize(cr));
+
+            match &mut to.chroma {
+                Chroma::Planar {
+                    cb: cb_plane,
+                    cr: cr_plane,
+                } => {
+                    cb_plane.set(i, j, cb);
+                    cr_plane.set(i, j, cr);
+                }
+                Chroma::SemiPlanar(cbcr) => {
+                    cbcr.set(2 * i, j, cb);
+                    cbcr.set(2 * i + 1, j, cr);
+                }
+            }
+        }
+    }
+}
+
+macro_rules! planar {
+    ($($ch: ident: $p: ty, $round: expr);* $(;)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Yuv<'_, $ch> {
+                /// Convert to an interleaved RGB image.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `to` is a different size.
+                pub fn unpack_rgb(
+                    &self,
+                    to: &mut PlaneMut<'_, Rgb<$ch>>,
+                    matrix: Matrix,
+                    range: Range,
+                    filter: Filter,
+                ) {
+                    let chan = planar!(@chan $ch, $p, $round);
+
+                    unpack(self, to, filter, chan, |p| {
+                        Decoding::<Ycbcr<$ch>, Rgb<$ch>>::decode(
+                            p, matrix, range,
+                        )
+                    });
+                }
+
+                /// Convert to an interleaved RGBA image (opaque).
+                ///
+                /// # Panics
+                ///
+                /// Panics if `to` is a different size.
+                pub fn unpack_rgba(
+                    &self,
+                    to: &mut PlaneMut<'_, Rgba<$ch>>,
+                    matrix: Matrix,
+                    range: Range,
+                    filter: Filter,
+                ) {
+                    let chan = planar!(@chan $ch, $p, $round);
+
+                    unpack(self, to, filter, chan, |p| {
+                        let rgb = Decoding::<Ycbcr<$ch>, Rgb<$ch>>::decode(
+                            p, matrix, range,
+                        );
+
+                        Conversion::<Rgb<$ch>, Rgba<$ch>>::convert(rgb)
+                    });
+                }
+            }
+
+            #[allow(trivial_numeric_casts)]
+            impl YuvMut<'_, $ch> {
+                /// Convert from an interleaved RGB image.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `from` is a different size.
+                pub fn pack_rgb(
+                    &mut self,
+                    from: &Plane<'_, Rgb<$ch>>,
+                    matrix: Matrix,
+                    range: Range,
+                    filter: Filter,
+                ) {
+                    let chan = planar!(@chan $ch, $p, $round);
+
+                    pack(self, from, filter, chan, |p| {
+                        Encoding::<Rgb<$ch>, Ycbcr<$ch>>::encode(
+                            p, matrix, range,
+                        )
+                    });
+                }
+
+                /// Convert from an interleaved RGBA image (discarding alpha).
+                ///
+                /// # Panics
+                ///
+                /// Panics if `from` is a different size.
+                pub fn pack_rgba(
+                    &mut self,
+                    from: &Plane<'_, Rgba<$ch>>,
+                    matrix: Matrix,
+                    range: Range,
+                    filter: Filter,
+                ) {
+                    let chan = planar!(@chan $ch, $p, $round);
+
+                    pack(self, from, filter, chan, |p| {
+                        let rgb = Conversion::<Rgba<$ch>, Rgb<$ch>>::convert(p);
+
+                        Encoding::<Rgb<$ch>, Ycbcr<$ch>>::encode(
+                            rgb, matrix, range,
+                        )
+                    });
+                }
+            }
+        )*
+    };
+    (@chan $ch: ident, $p: ty, $round: expr) => {
+        (
+            |chan: $ch| chan.into_inner() as f64,
+            |value: f64| $ch::new(($round)(value) as $p),
+        )
+    };
+}
+
+planar!(
+    Ch8: u8, math::round;
+    Ch10: u16, math::round;
+    Ch12: u16, math::round;
+    Ch16: u16, math::round;
+    Ch32: f32, core::convert::identity;
+    Ch64: f64, core::convert::identity;
+);
+
+impl Yuv<'_, P010> {
+    /// Convert to an interleaved 10-bit RGB image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` is a different size.
+    pub fn unpack_rgb(
+        &self,
+        to: &mut PlaneMut<'_, Rgb<Ch10>>,
+        matrix: Matrix,
+        range: Range,
+        filter: Filter,
+    ) {
+        unpack(self, to, filter, p010(), |p| {
+            let p = p.map(Conversion::<P010, Ch10>::convert);
+
+            Decoding::<Ycbcr<Ch10>, Rgb<Ch10>>::decode(p, matrix, range)
+        });
+    }
+
+    /// Convert to an interleaved 10-bit RGBA image (opaque).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `to` is a different size.
+    pub fn unpack_rgba(
+        &self,
+        to: &mut PlaneMut<'_, Rgba<Ch10>>,
+        matrix: Matrix,
+        range: Range,
+        filter: Filter,
+    ) {
+        unpack(self, to, filter, p010(), |p| {
+            let p = p.map(Conversion::<P010, Ch10>::convert);
+            let rgb =
+                Decoding::<Ycbcr<Ch10>, Rgb<Ch10>>::decode(p, matrix, range);
+
+            Conversion::<Rgb<Ch10>, Rgba<Ch10>>::convert(rgb)
+        });
+    }
+}
+
+impl YuvMut<'_, P010> {
+    /// Convert from an interleaved 10-bit RGB image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` is a different size.
+    pub fn pack_rgb(
+        &mut self,
+        from: &Plane<'_, Rgb<Ch10>>,
+        matrix: Matrix,
+        range: Range,
+        filter: Filter,
+    ) {
+        pack(self, from, filter, p010(), |p| {
+            Encoding::<Rgb<Ch10>, Ycbcr<Ch10>>::encode(p, matrix, range)
+                .map(Conversion::<Ch10, P010>::convert)
+        });
+    }
+
+    /// Convert from an interleaved 10-bit RGBA image (discarding alpha).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `from` is a different size.
+    pub fn pack_rgba(
+        &mut self,
+        from: &Plane<'_, Rgba<Ch10>>,
+        matrix: Matrix,
+        range: Range,
+        filter: Filter,
+    ) {
+        pack(self, from, filter, p010(), |p| {
+            let rgb = Conversion::<Rgba<Ch10>, Rgb<Ch10>>::convert(p);
+
+            Encoding::<Rgb<Ch10>, Ycbcr<Ch10>>::encode(rgb, matrix, range)
+                .map(Conversion::<Ch10, P010>::convert)
+        });
+    }
+}
+
+/// Get the code value of a P010 sample, and quantize a code value.
+fn p010() -> (impl Fn(P010) -> f64, impl Fn(f64) -> P010) {
+    (
+        |sample: P010| (sample.0 >> 6) as f64,
+        |value: f64| P010((math::round(value) as u16) << 6),
+    )
+}
@@ -0,0 +1,183 @@
+//! Limited ("studio") and full range remapping
+//!
+//! Limited range integer channels reserve footroom and headroom below black
+//! and above white (16 to 235 for 8-bit luma and RGB, and 16 to 240 for 8-bit
+//! chroma, scaled by 2ⁿ⁻⁸ for higher bit depths).  Integer channels are
+//! remapped between ranges (and bit depths) with [`Remapping`], correctly
+//! rounded:
+//!
+//! ```rust
+//! use p_chan::{
+//!     ops::Remapping,
+//!     range::{Component, Range, Remap},
+//!     unsigned::{Ch8, Ch10},
+//! };
+//!
+//! let remap = Remap {
+//!     from: Range::Limited,
+//!     to: Range::Full,
+//!     component: Component::Luma,
+//!     clip: false,
+//! };
+//! let chan = Remapping::<Ch8, Ch10>::remap(Ch8::new(126), remap);
+//!
+//! assert_eq!(chan.into_inner(), 514);
+//! ```
+
+use crate::{
+    ops::Remapping,
+    unsigned::{Ch8, Ch10, Ch12, Ch16},
+};
+
+/// Quantization range
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Range {
+    /// Full range (for example, 0 to 255 for 8-bit)
+    Full,
+    /// Limited "studio" range (for example, 16 to 235 luma and 16 to 240
+    /// chroma for 8-bit)
+    Limited,
+}
+
+impl Range {
+    /// Get the integer scale and offset (zero point) of a component for a bit
+    /// depth.
+    pub(crate) const fn scale_offset(
+        self,
+        component: Component,
+        bits: u32,
+    ) -> (i64, i64) {
+        let max = (1 << bits) - 1;
+
+        match (self, component) {
+            (Self::Full, Component::Luma) => (max, 0),
+            (Self::Full, Component::Chroma) => (max, 1 << (bits - 1)),
+            (Self::Limited, Component::Luma) => {
+                (219 << (bits - 8), 16 << (bits - 8))
+            }
+            (Self::Limited, Component::Chroma) => {
+                (224 << (bits - 8), 128 << (bits - 8))
+            }
+        }
+    }
+}
+
+/// Kind of component being remapped
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Component {
+    /// Luma or RGB (offset from zero)
+    Luma,
+    /// Chroma (offset from the center)
+    Chroma,
+}
+
+/// Range remapping parameters
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Remap {
+    /// Range of the input
+    pub from: Range,
+    /// Range of the output
+    pub to: Range,
+    /// Kind of component
+    pub component: Component,
+    /// Clip limited range values to the nominal range (removing super-black
+    /// and super-white)
+    ///
+    /// Otherwise, values outside of the nominal range are kept where the
+    /// output can represent them.
+    pub clip: bool,
+}
+
+impl Remap {
+    /// Remap a code value between bit depths (correctly rounded, ties up).
+    const fn remap(self, code: i64, from_bits: u32, to_bits: u32) -> i64 {
+        let (from_scale, from_offset) =
+            self.from.scale_offset(self.component, from_bits);
+        let (to_scale, to_offset) =
+            self.to.scale_offset(self.component, to_bits);
+        let code = if self.clip && matches!(self.from, Range::Limited) {
+            let min = from_offset - zero(self.component, from_scale);
+
+            clamp(code, min, min + from_scale)
+        } else {
+            code
+        };
+        let numerator =
+            (code - from_offset) * to_scale + to_offset * from_scale;
+        // Round half up
+        let code = (2 * numerator + from_scale).div_euclid(2 * from_scale);
+        let code = if self.clip && matches!(self.to, Range::Limited) {
+            let min = to_offset - zero(self.component, to_scale);
+
+            clamp(code, min, min + to_scale)
+        } else {
+            code
+        };
+
+        clamp(code, 0, (1 << to_bits) - 1)
+    }
+}
+
+/// Get the distance from the minimum to the zero point of a component.
+const fn zero(component: Component, scale: i64) -> i64 {
+    match component {
+        Component::Luma => 0,
+        Component::Chroma => scale / 2,
+    }
+}
+
+const fn clamp(code: i64, min: i64, max: i64) -> i64 {
+    if code < min {
+        min
+    } else if code > max {
+        max
+    } else {
+        code
+    }
+}
+
+macro_rules! remap {
+    ($($ch: ident: $p: ty, $bits: literal);* $(;)?) => {
+        remap!(@from [$($ch: $p, $bits);*] $($ch: $p, $bits);*);
+    };
+    (@from $all: tt $($from: ident: $fp: ty, $fbits: literal);*) => {
+        $(remap!(@to $from: $fp, $fbits, $all);)*
+    };
+    (
+        @to $from: ident: $fp: ty, $fbits: literal,
+        [$($to: ident: $tp: ty, $tbits: literal);*]
+    ) => {
+        $(
+            impl Remapping<$from, $to> {
+                /// Remap between ranges.
+                pub const fn remap(from: $from, remap: Remap) -> $to {
+                    let code = from.into_inner() as i64;
+
+                    $to::new(remap.remap(code, $fbits, $tbits) as $tp)
+                }
+
+                /// Remap a slice between ranges.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `from` and `to` have different lengths.
+                pub const fn remap_slice(
+                    from: &[$from],
+                    to: &mut [$to],
+                    remap: Remap,
+                ) {
+                    assert!(from.len() == to.len(), "slice lengths differ");
+
+                    let mut i = 0;
+
+                    while i < from.len() {
+                        to[i] = Self::remap(from[i], remap);
+                        i += 1;
+                    }
+                }
+            }
+        )*
+    };
+}
+
+remap!(Ch8: u8, 8; Ch10: u16, 10; Ch12: u16, 12; Ch16: u16, 16);
@@ -0,0 +1,265 @@
+//! Sample-rate conversion
+//!
+//! A [`Resampler`] converts a stream of floating-point channel values from one
+//! sample rate to another, block by block (use one resampler per channel).
+//! Output is delayed by [`Resampler::latency()`] input samples, which can be
+//! flushed out by processing that many samples of silence.
+//!
+//! ```rust
+//! use p_chan::{
+//!     resample::{Quality, Resampler},
+//!     signed::Ch32,
+//! };
+//!
+//! let mut resampler = Resampler::<Ch32>::new(Quality::Sinc, 44_100, 48_000);
+//! let input = [Ch32::new(0.5); 441];
+//! let mut output = [Ch32::new(0.0); 481];
+//! let written = resampler.process(&input, &mut output);
+//!
+//! // Output is delayed by 32 input samples
+//! assert_eq!(written, 446);
+//! assert!((output[written - 1].into_inner() - 0.5).abs() < 1e-6);
+//! ```
+
+use core::{f64::consts::PI, marker::PhantomData};
+
+use crate::math;
+
+/// Half of the number of windowed-sinc filter taps
+const SINC_HALF_TAPS: usize = 32;
+/// Length of input history (a power of two, at least twice
+/// [`SINC_HALF_TAPS`])
+const HISTORY: usize = 64;
+/// Windowed-sinc cutoff frequency, relative to the lower Nyquist frequency
+const CUTOFF: f64 = 0.9;
+
+/// Resampling quality (interpolation method)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Quality {
+    /// Linear interpolation (fastest, no anti-aliasing filter)
+    Linear,
+    /// Cubic (Catmull-Rom) interpolation (no anti-aliasing filter)
+    Cubic,
+    /// Blackman windowed-sinc low-pass filter (64 taps)
+    Sinc,
+}
+
+impl Quality {
+    /// Get the number of input samples needed after the interpolation point.
+    const fn half_taps(self) -> usize {
+        match self {
+            Self::Linear => 1,
+            Self::Cubic => 2,
+            Self::Sinc => SINC_HALF_TAPS,
+        }
+    }
+}
+
+/// Streaming sample-rate converter for one channel
+#[derive(Clone, Debug)]
+pub struct Resampler<C> {
+    quality: Quality,
+    /// Input rate (reduced)
+    from: u32,
+    /// Output rate (reduced)
+    to: u32,
+    /// Sinc cutoff frequency, relative to input Nyquist
+    cutoff: f64,
+    history: [f64; HISTORY],
+    /// Number of input samples received
+    received: u64,
+    /// Whole input sample position of the next output sample
+    position: u64,
+    /// Fractional input sample position of the next output sample (in units
+    /// of 1 / `to`)
+    remainder: u32,
+    _chan: PhantomData<fn() -> C>,
+}
+
+impl<C> Resampler<C> {
+    /// Create a new resampler from `from` Hz to `to` Hz.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either rate is zero.
+    pub const fn new(quality: Quality, from: u32, to: u32) -> Self {
+        assert!(from != 0 && to != 0, "invalid sample rate");
+
+        let divisor = gcd(from, to);
+        let (from, to) = (from / divisor, to / divisor);
+        let cutoff = if to < from {
+            CUTOFF * to as f64 / from as f64
+        } else {
+            CUTOFF
+        };
+
+        Self {
+            quality,
+            from,
+            to,
+            cutoff,
+            history: [0.0; HISTORY],
+            received: 0,
+            position: 0,
+            remainder: 0,
+            _chan: PhantomData,
+        }
+    }
+
+    /// Get the latency (in input samples).
+    pub const fn latency(&self) -> usize {
+        self.quality.half_taps()
+    }
+
+    /// Get the maximum number of output samples for a number of input
+    /// samples.
+    pub const fn max_output(&self, input: usize) -> usize {
+        (input as u64 * self.to as u64).div_ceil(self.from as u64) as usize + 1
+    }
+
+    /// Reset to the initial state (silent history).
+    pub const fn reset(&mut self) {
+        self.history = [0.0; HISTORY];
+        self.received = 0;
+        self.position = 0;
+        self.remainder = 0;
+    }
+
+    /// Process one input sample, calling `output` for each output sample.
+    fn push(&mut self, sample: f64, mut output: impl FnMut(f64)) {
+        self.history[self.received as usize % HISTORY] = sample;
+        self.received += 1;
+
+        let half_taps = self.quality.half_taps() as u64;
+
+        while self.position + half_taps < self.received {
+            output(self.interpolate());
+            self.remainder += self.from;
+            self.position += (self.remainder / self.to) as u64;
+            self.remainder %= self.to;
+        }
+    }
+
+    /// Get the input sample at `offset` from the current position.
+    fn sample(&self, offset: i64) -> f64 {
+        let index = (self.position as i64 + offset).rem_euclid(HISTORY as i64);
+
+        self.history[index as usize]
+    }
+
+    /// Interpolate the output sample at the current position.
+    fn interpolate(&self) -> f64 {
+        let fraction = self.remainder as f64 / self.to as f64;
+
+        match self.quality {
+            Quality::Linear => {
+                let (a, b) = (self.sample(0), self.sample(1));
+
+                a + (b - a) * fraction
+            }
+            Quality::Cubic => {
+                let s0 = self.sample(-1);
+                let s1 = self.sample(0);
+                let s2 = self.sample(1);
+                let s3 = self.sample(2);
+                let a = 3.0 * (s1 - s2) + s3 - s0;
+                let b = 2.0 * s0 - 5.0 * s1 + 4.0 * s2 - s3;
+
+                s1 + 0.5 * fraction * (s2 - s0 + fraction * (b + fraction * a))
+            }
+            Quality::Sinc => self.sinc(fraction),
+        }
+    }
+
+    /// Apply the windowed-sinc filter at a fractional position.
+    fn sinc(&self, fraction: f64) -> f64 {
+        let half_taps = SINC_HALF_TAPS as f64;
+        let first = 1 - SINC_HALF_TAPS as i64;
+        // Sinc and window angles are stepped by rotation
+        let x = first as f64 - fraction;
+        let (mut sinc_sin, mut sinc_cos) = math::sin_cos(PI * self.cutoff * x);
+        let (step_sin, step_cos) = math::sin_cos(PI * self.cutoff);
+        let (mut window_sin, mut window_cos) =
+            math::sin_cos(PI * x / half_taps);
+        let (window_step_sin, window_step_cos) = math::sin_cos(PI / half_taps);
+        let mut sum = 0.0;
+        let mut total = 0.0;
+
+        for offset in first..=SINC_HALF_TAPS as i64 {
+            let x = offset as f64 - fraction;
+            let sinc = if x == 0.0 {
+                1.0
+            } else {
+                sinc_sin / (PI * self.cutoff * x)
+            };
+            let window = 0.42
+                + 0.5 * window_cos
+                + 0.08 * (2.0 * window_cos * window_cos - 1.0);
+            let coefficient = sinc * window;
+
+            sum += self.sample(offset) * coefficient;
+            total += coefficient;
+            (sinc_sin, sinc_cos) = (
+                sinc_sin * step_cos + sinc_cos * step_sin,
+                sinc_cos * step_cos - sinc_sin * step_sin,
+            );
+            (window_sin, window_cos) = (
+                window_sin * window_step_cos + window_cos * window_step_sin,
+                window_cos * window_step_cos - window_sin * window_step_sin,
+            );
+        }
+
+        // Normalize for unity gain
+        sum / total
+    }
+}
+
+/// Get the greatest common divisor.
+const fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+macro_rules! resample {
+    ($($ch: ident: $p: ty),* $(,)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Resampler<$ch> {
+                /// Resample channel values.
+                ///
+                /// Returns the number of output channel values written.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `output` is too short (it can't be shorter than
+                /// [`Resampler::max_output()`]).
+                pub fn process(
+                    &mut self,
+                    input: &[$ch],
+                    output: &mut [$ch],
+                ) -> usize {
+                    let mut written = 0;
+
+                    for chan in input {
+                        self.push(chan.into_inner() as f64, |sample| {
+                            output[written] = $ch::new(sample as $p);
+                            written += 1;
+                        });
+                    }
+
+                    written
+                }
+            }
+        )*
+    };
+}
+
+mod signed {
+    use super::*;
+    use crate::signed::{Ch32, Ch64};
+
+    resample!(Ch32: f32, Ch64: f64);
+}
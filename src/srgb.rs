@@ -0,0 +1,100 @@
+//! sRGB-encoded channel newtypes
+//!
+//! These store the non-linear sRGB code value.  Converting to an unsigned
+//! floating-point channel with [`Conversion`] applies the piecewise sRGB EOTF
+//! to get linear light, and converting back applies the inverse.  8-bit
+//! channels use `const` lookup tables, and 16-bit channels are calculated
+//! (rounded to the nearest code).
+//!
+//! Arithmetic on these types happens on the encoded values; convert to
+//! floating-point channels first to blend in linear light.
+
+use crate::{
+    ops::Conversion,
+    transfer::Transfer,
+    unsigned::{self, ChN, midpoint},
+};
+
+ch_int!(
+    (Ch8, u8, u16, ChN<8>, midpoint! {}),
+    doc = "8-bit sRGB-encoded (0 to 255) unsigned integer channel value",
+);
+
+ch_int!(
+    (Ch16, u16, u32, ChN<16>, midpoint! {}),
+    doc = "16-bit sRGB-encoded (0 to 65\\_535) unsigned integer channel value",
+);
+
+/// Linear light for each 8-bit code
+const DECODE_8: [f64; 256] = {
+    let mut table = [0.0; 256];
+    let mut i = 0;
+
+    while i < table.len() {
+        table[i] = Transfer::Srgb.decode(i as f64 / 255.0);
+        i += 1;
+    }
+
+    table
+};
+
+/// Linear light halfway between each 8-bit code and the next
+const ENCODE_8: [f64; 255] = {
+    let mut table = [0.0; 255];
+    let mut i = 0;
+
+    while i < table.len() {
+        table[i] = Transfer::Srgb.decode((i as f64 + 0.5) / 255.0);
+        i += 1;
+    }
+
+    table
+};
+
+/// Find the nearest 8-bit code for linear light.
+const fn encode_8(linear: f64) -> Ch8 {
+    // Binary search for the number of thresholds below `linear`
+    let (mut low, mut high) = (0, ENCODE_8.len());
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+
+        if ENCODE_8[mid] <= linear {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    Ch8::new(low as u8)
+}
+
+impl Conversion<Ch8, unsigned::Ch32> {
+    /// Convert between types.
+    pub const fn convert(from: Ch8) -> unsigned::Ch32 {
+        unsigned::Ch32::new(DECODE_8[from.into_inner() as usize] as f32)
+    }
+}
+
+impl Conversion<Ch8, unsigned::Ch64> {
+    /// Convert between types.
+    pub const fn convert(from: Ch8) -> unsigned::Ch64 {
+        unsigned::Ch64::new(DECODE_8[from.into_inner() as usize])
+    }
+}
+
+impl Conversion<unsigned::Ch32, Ch8> {
+    /// Convert between types.
+    pub const fn convert(from: unsigned::Ch32) -> Ch8 {
+        encode_8(from.into_inner() as f64)
+    }
+}
+
+impl Conversion<unsigned::Ch64, Ch8> {
+    /// Convert between types.
+    pub const fn convert(from: unsigned::Ch64) -> Ch8 {
+        encode_8(from.into_inner())
+    }
+}
+
+transfer!(Ch16, u16, 65_535.0, Transfer::Srgb);
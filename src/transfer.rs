@@ -0,0 +1,275 @@
+//! Transfer functions (non-linear channel encodings)
+//!
+//! A [`Transfer`] describes how linear light is encoded into a channel value.
+//! Each curve has encoded channel newtypes (in the submodules here, and the
+//! [`srgb`](crate::srgb) and [`hdr`](crate::hdr) modules), which decode to and
+//! encode from linear unsigned floating-point channels with [`Conversion`]:
+//!
+//! ```rust
+//! use p_chan::{ops::Conversion, transfer::cineon, unsigned};
+//!
+//! let code = cineon::Ch10::new(685);
+//! let linear = Conversion::<cineon::Ch10, unsigned::Ch32>::convert(code);
+//!
+//! assert_eq!(linear.into_inner(), 1.0);
+//! ```
+//!
+//! Other curves (such as a custom gamma) can be applied to floating-point
+//! values directly with [`Transfer::decode()`] and [`Transfer::encode()`].
+//!
+//! [`Conversion`]: crate::ops::Conversion
+
+use crate::math;
+
+/// Cineon reference black code value (out of 1023)
+const CINEON_BLACK: f64 = 95.0;
+/// Cineon reference white code value (out of 1023)
+const CINEON_WHITE: f64 = 685.0;
+/// Cineon printing density per code value, divided by negative gamma
+const CINEON_DENSITY: f64 = 0.002 / 0.6;
+/// ACEScct toe slope
+const ACESCCT_A: f64 = 10.540_237_741_654_5;
+/// ACEScct toe offset
+const ACESCCT_B: f64 = 0.072_905_534_195_835_5;
+/// ACEScct linear break point
+const ACESCCT_X_BRK: f64 = 0.007_812_5;
+/// ACEScct encoded break point
+const ACESCCT_Y_BRK: f64 = 0.155_251_141_552_511;
+/// Largest half-float value (ACES maximum)
+const ACES_MAX: f64 = 65_504.0;
+/// PQ constants
+const PQ_M1: f64 = 2_610.0 / 16_384.0;
+const PQ_M2: f64 = 2_523.0 / 4_096.0 * 128.0;
+const PQ_C1: f64 = 3_424.0 / 4_096.0;
+const PQ_C2: f64 = 2_413.0 / 4_096.0 * 32.0;
+const PQ_C3: f64 = 2_392.0 / 4_096.0 * 32.0;
+/// HLG constants
+const HLG_A: f64 = 0.178_832_77;
+const HLG_B: f64 = 0.284_668_92;
+const HLG_C: f64 = 0.559_910_73;
+
+/// Transfer function between linear light and an encoded channel value
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Transfer {
+    /// No encoding
+    Linear,
+    /// Piecewise sRGB curve (IEC 61966-2-1)
+    Srgb,
+    /// Pure power-law gamma (linear = encoded ^ gamma)
+    Gamma(f64),
+    /// Perceptual quantizer (SMPTE ST 2084), `1.0` linear is 10\_000 cd/m²
+    Pq,
+    /// Hybrid log-gamma (ITU-R BT.2100) OETF, with relative scene linear
+    Hlg,
+    /// Cineon / DPX printing density log (black at 95, white at 685 out of
+    /// 1023)
+    Cineon,
+    /// ACEScct log (S-2016-001)
+    AcesCct,
+}
+
+impl Transfer {
+    /// Decode an encoded value (nominally 0 to 1) to linear light.
+    pub const fn decode(self, encoded: f64) -> f64 {
+        match self {
+            Self::Linear => encoded,
+            Self::Srgb => {
+                if encoded <= 0.040_45 {
+                    encoded / 12.92
+                } else {
+                    math::pow((encoded + 0.055) / 1.055, 2.4)
+                }
+            }
+            Self::Gamma(gamma) => math::pow(encoded, gamma),
+            Self::Pq => {
+                let power = math::pow(encoded, 1.0 / PQ_M2);
+                let numerator = (power - PQ_C1).max(0.0);
+
+                math::pow(numerator / (PQ_C2 - PQ_C3 * power), 1.0 / PQ_M1)
+            }
+            Self::Hlg => {
+                if encoded <= 0.5 {
+                    encoded * encoded / 3.0
+                } else {
+                    (math::exp((encoded - HLG_C) / HLG_A) + HLG_B) / 12.0
+                }
+            }
+            Self::Cineon => {
+                let offset = cineon_offset();
+                let density =
+                    (encoded * 1_023.0 - CINEON_WHITE) * CINEON_DENSITY;
+
+                (math::exp(density * core::f64::consts::LN_10) - offset)
+                    / (1.0 - offset)
+            }
+            Self::AcesCct => {
+                if encoded <= ACESCCT_Y_BRK {
+                    (encoded - ACESCCT_B) / ACESCCT_A
+                } else {
+                    let exponent = encoded * 17.52 - 9.72;
+
+                    math::exp(exponent * core::f64::consts::LN_2).min(ACES_MAX)
+                }
+            }
+        }
+    }
+
+    /// Encode linear light to an encoded value (nominally 0 to 1).
+    pub const fn encode(self, linear: f64) -> f64 {
+        match self {
+            Self::Linear => linear,
+            Self::Srgb => {
+                if linear <= 0.003_130_8 {
+                    linear * 12.92
+                } else {
+                    1.055 * math::pow(linear, 1.0 / 2.4) - 0.055
+                }
+            }
+            Self::Gamma(gamma) => math::pow(linear, 1.0 / gamma),
+            Self::Pq => {
+                let power = math::pow(linear, PQ_M1);
+
+                math::pow(
+                    (PQ_C1 + PQ_C2 * power) / (1.0 + PQ_C3 * power),
+                    PQ_M2,
+                )
+            }
+            Self::Hlg => {
+                if linear <= 1.0 / 12.0 {
+                    math::sqrt(3.0 * linear)
+                } else {
+                    HLG_A * math::ln(12.0 * linear - HLG_B) + HLG_C
+                }
+            }
+            Self::Cineon => {
+                let offset = cineon_offset();
+                let log = math::ln(linear * (1.0 - offset) + offset)
+                    / core::f64::consts::LN_10;
+
+                (CINEON_WHITE + log / CINEON_DENSITY) / 1_023.0
+            }
+            Self::AcesCct => {
+                if linear <= ACESCCT_X_BRK {
+                    ACESCCT_A * linear + ACESCCT_B
+                } else {
+                    (math::ln(linear) / core::f64::consts::LN_2 + 9.72) / 17.52
+                }
+            }
+        }
+    }
+}
+
+/// Linear light offset so that reference black decodes to zero
+const fn cineon_offset() -> f64 {
+    let density = (CINEON_BLACK - CINEON_WHITE) * CINEON_DENSITY;
+
+    math::exp(density * core::f64::consts::LN_10)
+}
+
+macro_rules! encoded {
+    (
+        $transfer: expr;
+        $(
+            ($ty: ident, $p: ty, $b: ty, $n: ty, $max: literal),
+            $doc: literal;
+        )*
+    ) => {
+        $(
+            ch_int!(($ty, $p, $b, $n, midpoint! {}), doc = $doc);
+            transfer!($ty, $p, $max, $transfer);
+        )*
+    };
+}
+
+pub mod gamma22 {
+    //! Power-law gamma 2.2 encoded channel newtypes
+
+    use super::Transfer;
+    use crate::unsigned::{ChN, midpoint};
+
+    encoded!(
+        Transfer::Gamma(2.2);
+        (Ch8, u8, u16, ChN<8>, 255.0),
+        "8-bit gamma 2.2 (0 to 255) unsigned integer channel value";
+        (Ch10, u16, u32, ChN<10>, 1_023.0),
+        "10-bit gamma 2.2 (0 to 1\\_023) unsigned integer channel value";
+        (Ch12, u16, u32, ChN<12>, 4_095.0),
+        "12-bit gamma 2.2 (0 to 4\\_095) unsigned integer channel value";
+        (Ch16, u16, u32, ChN<16>, 65_535.0),
+        "16-bit gamma 2.2 (0 to 65\\_535) unsigned integer channel value";
+    );
+}
+
+pub mod gamma24 {
+    //! Power-law gamma 2.4 (ITU-R BT.1886) encoded channel newtypes
+
+    use super::Transfer;
+    use crate::unsigned::{ChN, midpoint};
+
+    encoded!(
+        Transfer::Gamma(2.4);
+        (Ch8, u8, u16, ChN<8>, 255.0),
+        "8-bit gamma 2.4 (0 to 255) unsigned integer channel value";
+        (Ch10, u16, u32, ChN<10>, 1_023.0),
+        "10-bit gamma 2.4 (0 to 1\\_023) unsigned integer channel value";
+        (Ch12, u16, u32, ChN<12>, 4_095.0),
+        "12-bit gamma 2.4 (0 to 4\\_095) unsigned integer channel value";
+        (Ch16, u16, u32, ChN<16>, 65_535.0),
+        "16-bit gamma 2.4 (0 to 65\\_535) unsigned integer channel value";
+    );
+}
+
+pub mod gamma26 {
+    //! Power-law gamma 2.6 (DCI) encoded channel newtypes
+
+    use super::Transfer;
+    use crate::unsigned::{ChN, midpoint};
+
+    encoded!(
+        Transfer::Gamma(2.6);
+        (Ch8, u8, u16, ChN<8>, 255.0),
+        "8-bit gamma 2.6 (0 to 255) unsigned integer channel value";
+        (Ch10, u16, u32, ChN<10>, 1_023.0),
+        "10-bit gamma 2.6 (0 to 1\\_023) unsigned integer channel value";
+        (Ch12, u16, u32, ChN<12>, 4_095.0),
+        "12-bit gamma 2.6 (0 to 4\\_095) unsigned integer channel value";
+        (Ch16, u16, u32, ChN<16>, 65_535.0),
+        "16-bit gamma 2.6 (0 to 65\\_535) unsigned integer channel value";
+    );
+}
+
+pub mod cineon {
+    //! Cineon / DPX printing density log encoded channel newtypes
+    //!
+    //! Reference black (code 95) decodes to `0.0`, and reference white (code
+    //! 685) to `1.0`.
+
+    use super::Transfer;
+    use crate::unsigned::{ChN, midpoint};
+
+    encoded!(
+        Transfer::Cineon;
+        (Ch10, u16, u32, ChN<10>, 1_023.0),
+        "10-bit Cineon log (0 to 1\\_023) unsigned integer channel value";
+    );
+}
+
+pub mod acescct {
+    //! ACEScct log encoded channel newtypes
+    //!
+    //! Linear light is scene-referred, so can exceed `1.0` (up to the half
+    //! float maximum).
+
+    use super::Transfer;
+    use crate::unsigned::{ChN, midpoint};
+
+    encoded!(
+        Transfer::AcesCct;
+        (Ch10, u16, u32, ChN<10>, 1_023.0),
+        "10-bit ACEScct encoded (0 to 1\\_023) unsigned integer channel value";
+        (Ch12, u16, u32, ChN<12>, 4_095.0),
+        "12-bit ACEScct encoded (0 to 4\\_095) unsigned integer channel value";
+        (Ch16, u16, u32, ChN<16>, 65_535.0),
+        "16-bit ACEScct encoded (0 to 65\\_535) unsigned integer channel value";
+    );
+}
@@ -0,0 +1,249 @@
+//! Y′CbCr color matrices
+//!
+//! [`Ycbcr`] pixels are decoded to (non-linear) [`Rgb`] pixels of the same
+//! channel type with [`Decoding`], and encoded from them with [`Encoding`]:
+//!
+//! ```rust
+//! use p_chan::{
+//!     ops::Decoding,
+//!     pixel::{Rgb, Ycbcr},
+//!     unsigned::Ch8,
+//!     ycbcr::{Matrix, Range},
+//! };
+//!
+//! let pixel = Ycbcr::new(Ch8::new(235), Ch8::new(128), Ch8::new(128));
+//! let rgb = Decoding::<Ycbcr<Ch8>, Rgb<Ch8>>::decode(
+//!     pixel,
+//!     Matrix::Bt709,
+//!     Range::Limited,
+//! );
+//!
+//! assert_eq!(rgb, Rgb::<Ch8>::WHITE);
+//! ```
+//!
+//! Integer channels are quantized as specified by ITU-R BT.2100 (including
+//! full range chroma centered on 2ⁿ⁻¹).  Floating-point channels store chroma
+//! offset by 0.5 for full range, and use 8-bit code values divided by 255 for
+//! limited range.
+
+pub use crate::range::Range;
+use crate::{
+    math,
+    ops::{Decoding, Encoding},
+    pixel::{Rgb, Ycbcr},
+    range::Component,
+    unsigned::{Ch8, Ch10, Ch12, Ch16, Ch32, Ch64},
+};
+
+/// Color matrix (luma coefficients)
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Matrix {
+    /// ITU-R BT.601 (standard definition)
+    Bt601,
+    /// ITU-R BT.709 (high definition)
+    Bt709,
+    /// ITU-R BT.2020 / BT.2100 non-constant luminance (ultra high definition)
+    Bt2020,
+}
+
+impl Matrix {
+    /// Get red and blue luma coefficients.
+    const fn coefficients(self) -> (f64, f64) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+            Self::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+
+    /// Convert normalized `[y, cb, cr]` (chroma from -0.5 to 0.5) to
+    /// `[r, g, b]`.
+    const fn to_rgb(self, [y, cb, cr]: [f64; 3]) -> [f64; 3] {
+        let (kr, kb) = self.coefficients();
+        let r = y + 2.0 * (1.0 - kr) * cr;
+        let b = y + 2.0 * (1.0 - kb) * cb;
+        let g = (y - kr * r - kb * b) / (1.0 - kr - kb);
+
+        [r, g, b]
+    }
+
+    /// Convert `[r, g, b]` to normalized `[y, cb, cr]` (chroma from -0.5 to
+    /// 0.5).
+    const fn to_ycbcr(self, [r, g, b]: [f64; 3]) -> [f64; 3] {
+        let (kr, kb) = self.coefficients();
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+
+        [
+            y,
+            (b - y) / (2.0 * (1.0 - kb)),
+            (r - y) / (2.0 * (1.0 - kr)),
+        ]
+    }
+}
+
+/// Get the scale and offset of normalized luma and chroma for a bit depth.
+const fn quantization(range: Range, bits: u32) -> [(f64, f64); 2] {
+    let (ys, yo) = range.scale_offset(Component::Luma, bits);
+    let (cs, co) = range.scale_offset(Component::Chroma, bits);
+
+    [(ys as f64, yo as f64), (cs as f64, co as f64)]
+}
+
+/// Get the scale and offset of normalized luma and chroma for floating-point
+/// channels.
+const fn float_quantization(range: Range) -> [(f64, f64); 2] {
+    match range {
+        Range::Full => [(1.0, 0.0), (1.0, 0.5)],
+        Range::Limited => {
+            let [(ys, yo), (cs, co)] = quantization(range, 8);
+
+            [(ys / 255.0, yo / 255.0), (cs / 255.0, co / 255.0)]
+        }
+    }
+}
+
+/// Convert code values to normalized `[y, cb, cr]`.
+const fn dequantize(
+    codes: [f64; 3],
+    [(ys, yo), (cs, co)]: [(f64, f64); 2],
+) -> [f64; 3] {
+    [
+        (codes[0] - yo) / ys,
+        (codes[1] - co) / cs,
+        (codes[2] - co) / cs,
+    ]
+}
+
+/// Convert normalized `[y, cb, cr]` to (unrounded) code values.
+const fn quantize(
+    ycbcr: [f64; 3],
+    [(ys, yo), (cs, co)]: [(f64, f64); 2],
+) -> [f64; 3] {
+    [ycbcr[0] * ys + yo, ycbcr[1] * cs + co, ycbcr[2] * cs + co]
+}
+
+/// Round and clamp to an integer code value.
+const fn round(code: f64, max: f64) -> f64 {
+    math::round(code.clamp(0.0, max))
+}
+
+macro_rules! ycbcr_int {
+    ($($ch: ident: $p: ty, $bits: literal);* $(;)?) => {
+        $(
+            impl Decoding<Ycbcr<$ch>, Rgb<$ch>> {
+                /// Decode to RGB (clamped).
+                pub const fn decode(
+                    from: Ycbcr<$ch>,
+                    matrix: Matrix,
+                    range: Range,
+                ) -> Rgb<$ch> {
+                    let codes = [
+                        from.y.into_inner() as f64,
+                        from.cb.into_inner() as f64,
+                        from.cr.into_inner() as f64,
+                    ];
+                    let max = $ch::MAX.into_inner() as f64;
+                    let quantization = quantization(range, $bits);
+                    let [r, g, b] =
+                        matrix.to_rgb(dequantize(codes, quantization));
+
+                    Rgb::new(
+                        $ch::new(round(r * max, max) as $p),
+                        $ch::new(round(g * max, max) as $p),
+                        $ch::new(round(b * max, max) as $p),
+                    )
+                }
+            }
+
+            impl Encoding<Rgb<$ch>, Ycbcr<$ch>> {
+                /// Encode from RGB (clamped).
+                pub const fn encode(
+                    from: Rgb<$ch>,
+                    matrix: Matrix,
+                    range: Range,
+                ) -> Ycbcr<$ch> {
+                    let max = $ch::MAX.into_inner() as f64;
+                    let rgb = [
+                        from.r.into_inner() as f64 / max,
+                        from.g.into_inner() as f64 / max,
+                        from.b.into_inner() as f64 / max,
+                    ];
+                    let quantization = quantization(range, $bits);
+                    let [y, cb, cr] =
+                        quantize(matrix.to_ycbcr(rgb), quantization);
+
+                    Ycbcr::new(
+                        $ch::new(round(y, max) as $p),
+                        $ch::new(round(cb, max) as $p),
+                        $ch::new(round(cr, max) as $p),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! ycbcr_float {
+    ($($ch: ident: $p: ty);* $(;)?) => {
+        $(
+            #[allow(trivial_numeric_casts)]
+            impl Decoding<Ycbcr<$ch>, Rgb<$ch>> {
+                /// Decode to RGB.
+                pub const fn decode(
+                    from: Ycbcr<$ch>,
+                    matrix: Matrix,
+                    range: Range,
+                ) -> Rgb<$ch> {
+                    let codes = [
+                        from.y.into_inner() as f64,
+                        from.cb.into_inner() as f64,
+                        from.cr.into_inner() as f64,
+                    ];
+                    let quantization = float_quantization(range);
+                    let [r, g, b] =
+                        matrix.to_rgb(dequantize(codes, quantization));
+
+                    Rgb::new(
+                        $ch::new(r as $p),
+                        $ch::new(g as $p),
+                        $ch::new(b as $p),
+                    )
+                }
+            }
+
+            #[allow(trivial_numeric_casts)]
+            impl Encoding<Rgb<$ch>, Ycbcr<$ch>> {
+                /// Encode from RGB.
+                pub const fn encode(
+                    from: Rgb<$ch>,
+                    matrix: Matrix,
+                    range: Range,
+                ) -> Ycbcr<$ch> {
+                    let rgb = [
+                        from.r.into_inner() as f64,
+                        from.g.into_inner() as f64,
+                        from.b.into_inner() as f64,
+                    ];
+                    let quantization = float_quantization(range);
+                    let [y, cb, cr] =
+                        quantize(matrix.to_ycbcr(rgb), quantization);
+
+                    Ycbcr::new(
+                        $ch::new(y as $p),
+                        $ch::new(cb as $p),
+                        $ch::new(cr as $p),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+ycbcr_int!(
+    Ch8: u8, 8;
+    Ch10: u16, 10;
+    Ch12: u16, 12;
+    Ch16: u16, 16;
+);
+
+ycbcr_float!(Ch32: f32; Ch64: f64);
@@ -0,0 +1,137 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
+use p_chan::{ops::Conversion, signed, unsigned};
+
+#[test]
+fn round_to_nearest_even() {
+    let bits = |float| {
+        Conversion::<unsigned::Ch32, unsigned::Bf16>::convert(
+            unsigned::Ch32::new(float),
+        )
+        .to_bits()
+    };
+
+    assert_eq!(bits(0.0), 0);
+    assert_eq!(bits(1.0), 0x3f80);
+    assert_eq!(bits(0.5), 0x3f00);
+    assert_eq!(bits(-1.0), 0xbf80);
+    // Ties to even
+    assert_eq!(bits(f32::from_bits(0x3f80_8000)), 0x3f80);
+    assert_eq!(bits(f32::from_bits(0x3f81_8000)), 0x3f82);
+    // Nearest
+    assert_eq!(bits(f32::from_bits(0x3f80_8001)), 0x3f81);
+    assert_eq!(bits(f32::from_bits(0x3f80_7fff)), 0x3f80);
+    // Overflow
+    assert_eq!(bits(f32::MAX), 0x7f80);
+    assert_eq!(bits(f32::INFINITY), 0x7f80);
+    assert_eq!(bits(f32::NEG_INFINITY), 0xff80);
+    // Flush
+    assert_eq!(bits(f32::NAN), 0);
+    assert_eq!(bits(-0.0), 0);
+    assert_eq!(bits(f32::MIN_POSITIVE / 2.0), 0);
+    assert_eq!(unsigned::Bf16::from_bits(0x7fc0).to_bits(), 0);
+    assert_eq!(unsigned::Bf16::from_bits(0x0001).to_bits(), 0);
+}
+
+#[test]
+fn ranges() {
+    assert_eq!(unsigned::Bf16::MIN.into_inner(), 0.0);
+    assert_eq!(unsigned::Bf16::MID.into_inner(), 0.5);
+    assert_eq!(unsigned::Bf16::MAX.into_inner(), 1.0);
+    assert_eq!(signed::Bf16::MIN.into_inner(), -1.0);
+    assert_eq!(signed::Bf16::MID.into_inner(), 0.0);
+    assert_eq!(signed::Bf16::MAX.into_inner(), 1.0);
+    assert!(signed::Bf16::MIN < signed::Bf16::MID);
+    assert_eq!(
+        signed::Bf16::new(-2.0).clamp(signed::Bf16::MIN, signed::Bf16::MAX),
+        signed::Bf16::MIN,
+    );
+    assert_eq!(
+        signed::Bf16::MIN.midpoint(signed::Bf16::MAX),
+        signed::Bf16::MID,
+    );
+    assert_eq!(
+        (unsigned::Bf16::MID + unsigned::Bf16::MID).into_inner(),
+        1.0,
+    );
+}
+
+#[test]
+fn from_integers() {
+    let convert = |int| {
+        Conversion::<unsigned::Ch8, unsigned::Bf16>::convert(
+            unsigned::Ch8::new(int),
+        )
+        .into_inner()
+    };
+
+    assert_eq!(convert(0), 0.0);
+    assert_eq!(convert(255), 1.0);
+    assert_eq!(convert(51), 0.200_195_31);
+    assert_eq!(
+        Conversion::<unsigned::Ch16, unsigned::Bf16>::convert(
+            unsigned::Ch16::MAX,
+        )
+        .into_inner(),
+        1.0,
+    );
+    assert_eq!(
+        Conversion::<signed::Ch8, signed::Bf16>::convert(signed::Ch8::MIN)
+            .into_inner(),
+        -1.0,
+    );
+    assert_eq!(
+        Conversion::<signed::Ch24, signed::Bf16>::convert(signed::Ch24::MAX)
+            .into_inner(),
+        1.0,
+    );
+}
+
+#[test]
+fn slices() {
+    let ints = [0, 51, 102, 255].map(unsigned::Ch8::new);
+    let mut bf16 = [unsigned::Bf16::MIN; 4];
+    let mut floats = [unsigned::Ch32::MIN; 4];
+
+    Conversion::<unsigned::Ch8, unsigned::Bf16>::convert_slice(
+        &ints, &mut bf16,
+    );
+    Conversion::<unsigned::Bf16, unsigned::Ch32>::convert_slice(
+        &bf16,
+        &mut floats,
+    );
+
+    assert_eq!(
+        floats.map(unsigned::Ch32::into_inner),
+        [0.0, 0.200_195_31, 0.400_390_63, 1.0],
+    );
+
+    Conversion::<unsigned::Ch32, unsigned::Bf16>::convert_slice(
+        &floats, &mut bf16,
+    );
+
+    assert_eq!(
+        bf16.map(unsigned::Bf16::to_bits),
+        [0, 0x3e4d, 0x3ecd, 0x3f80]
+    );
+}
+
+#[test]
+#[should_panic(expected = "slice lengths differ")]
+fn slices_length_mismatch() {
+    Conversion::<signed::Ch32, signed::Bf16>::convert_slice(
+        &[signed::Ch32::MIN; 2],
+        &mut [signed::Bf16::MIN; 3],
+    );
+}
+
+#[test]
+fn primitive() {
+    let chan = signed::Bf16::from(-0.25);
+
+    assert_eq!(chan, signed::Bf16::new(-0.25));
+    assert_eq!(chan.into_inner(), -0.25);
+    assert_eq!(f32::from(chan), -0.25);
+    assert_eq!(signed::Bf16::from_bits(chan.to_bits()), chan);
+    assert_eq!(unsigned::Bf16::from(1.0).to_bits(), 0x3f80);
+}
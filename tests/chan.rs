@@ -1,5 +1,6 @@
 use p_chan::chan::{
-    f32_to_i32, f32_to_u32, i32_to_f32, i32_to_u32, u32_to_f32, u32_to_i32,
+    f32_to_i32, f32_to_u32, i10_to_i32, i12_to_i32, i32_to_f32, i32_to_u32,
+    int_to_i32, u10_to_u32, u12_to_u32, u32_to_f32, u32_to_i32, uint_to_u32,
 };
 
 #[test]
@@ -146,3 +147,57 @@ fn signed_to_unsigned() {
     assert_eq!(i32_to_u32(0), u32::MAX / 2 + 1);
     assert_eq!(i32_to_u32(i32::MAX), u32::MAX);
 }
+
+#[test]
+fn upscale_10_bit() {
+    assert_eq!(u10_to_u32(0), u32::MIN);
+    assert_eq!(
+        u10_to_u32(0b10_0000_0000),
+        0b1000_0000_0010_0000_0000_1000_0000_0010
+    );
+    assert_eq!(u10_to_u32(1_023), u32::MAX);
+    assert_eq!(u10_to_u32(u16::MAX), u32::MAX);
+    assert_eq!(i10_to_i32(-512), i32::MIN);
+    assert_eq!(i10_to_i32(0), 0b0000_0000_0010_0000_0000_1000_0000_0010);
+    assert_eq!(i10_to_i32(511), i32::MAX);
+    assert_eq!(i10_to_i32(i16::MAX), i32::MAX);
+}
+
+#[test]
+fn upscale_12_bit() {
+    assert_eq!(u12_to_u32(0), u32::MIN);
+    assert_eq!(
+        u12_to_u32(0b1000_0000_0001),
+        0b1000_0000_0001_1000_0000_0001_1000_0000,
+    );
+    assert_eq!(u12_to_u32(4_095), u32::MAX);
+    assert_eq!(i12_to_i32(-2_048), i32::MIN);
+    assert_eq!(i12_to_i32(2_047), i32::MAX);
+}
+
+#[test]
+fn upscale_any_bits() {
+    assert_eq!(uint_to_u32(1, 1), u32::MAX);
+    assert_eq!(
+        uint_to_u32(0b101, 3),
+        0b1011_0110_1101_1011_0110_1101_1011_0110
+    );
+    assert_eq!(uint_to_u32(0x1ff, 9), u32::MAX);
+    assert_eq!(uint_to_u32(0x100, 9), 0x8040_2010);
+    assert_eq!(uint_to_u32(12_345, 32), 12_345);
+
+    for bits in 1..=32 {
+        assert_eq!(uint_to_u32(u32::MAX, bits), u32::MAX);
+        assert_eq!(uint_to_u32(0, bits), 0);
+        assert_eq!(int_to_i32(i32::MAX, bits), i32::MAX);
+        assert_eq!(int_to_i32(i32::MIN, bits), i32::MIN);
+    }
+
+    for i in -512..512 {
+        assert_eq!(int_to_i32(i, 10), i10_to_i32(i as i16));
+    }
+
+    for i in -2_048..2_048 {
+        assert_eq!(int_to_i32(i, 12), i12_to_i32(i as i16));
+    }
+}
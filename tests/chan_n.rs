@@ -0,0 +1,131 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
+use p_chan::{ops::Conversion, signed, unsigned};
+
+#[test]
+fn ranges() {
+    assert_eq!(unsigned::ChN::<1>::MAX.into_inner(), 1);
+    assert_eq!(unsigned::ChN::<9>::MIN.into_inner(), 0);
+    assert_eq!(unsigned::ChN::<9>::MID.into_inner(), 255);
+    assert_eq!(unsigned::ChN::<9>::MAX.into_inner(), 511);
+    assert_eq!(unsigned::ChN::<32>::MAX.into_inner(), u32::MAX);
+    assert_eq!(signed::ChN::<1>::MIN.into_inner(), -1);
+    assert_eq!(signed::ChN::<1>::MAX.into_inner(), 0);
+    assert_eq!(signed::ChN::<20>::MIN.into_inner(), -524_288);
+    assert_eq!(signed::ChN::<20>::MID.into_inner(), -1);
+    assert_eq!(signed::ChN::<20>::MAX.into_inner(), 524_287);
+    assert_eq!(signed::ChN::<32>::MIN.into_inner(), i32::MIN);
+    assert_eq!(signed::ChN::<32>::MAX.into_inner(), i32::MAX);
+}
+
+#[test]
+fn normalize() {
+    assert_eq!(unsigned::ChN::<11>::new(0x0fff).into_inner(), 0x07ff);
+    assert_eq!(signed::ChN::<11>::new(5_000).into_inner(), 1_023);
+    assert_eq!(signed::ChN::<11>::new(-5_000).into_inner(), -1_024);
+}
+
+#[test]
+fn saturate() {
+    let a = unsigned::ChN::<9>::new(400);
+    let b = signed::ChN::<9>::new(200);
+
+    assert_eq!(a + a, unsigned::ChN::MAX);
+    assert_eq!(a - a - a, unsigned::ChN::MIN);
+    assert_eq!(b + b, signed::ChN::MAX);
+    assert_eq!(signed::ChN::new(-200) - b - b, signed::ChN::MIN);
+}
+
+#[test]
+fn convert_depth() {
+    let chan = unsigned::ChN::<11>::new(0b100_0000_0001);
+    let wide =
+        Conversion::<unsigned::ChN<11>, unsigned::ChN<20>>::convert(chan);
+
+    assert_eq!(wide.into_inner(), 0b1000_0000_0011_0000_0000);
+    assert_eq!(
+        Conversion::<unsigned::ChN<20>, unsigned::ChN<11>>::convert(wide)
+            .into_inner(),
+        chan.into_inner(),
+    );
+    assert_eq!(
+        Conversion::<signed::ChN<9>, signed::ChN<20>>::convert(
+            signed::ChN::MAX
+        ),
+        signed::ChN::MAX,
+    );
+    assert_eq!(
+        Conversion::<signed::ChN<9>, signed::ChN<20>>::convert(
+            signed::ChN::MIN
+        ),
+        signed::ChN::MIN,
+    );
+}
+
+#[test]
+fn fixed_depths() {
+    // Fixed bit depths share the range and conversions of `ChN`
+    assert_eq!(
+        unsigned::Ch12::new(5_000).into_inner() as u32,
+        unsigned::ChN::<12>::new(5_000).into_inner(),
+    );
+    assert_eq!(
+        unsigned::Ch12::MAX.into_inner() as u32,
+        unsigned::ChN::<12>::MAX.into_inner(),
+    );
+    assert_eq!(signed::Ch24::new(i32::MIN), signed::Ch24::MIN);
+    assert_eq!(
+        signed::Ch24::MIN.into_inner(),
+        signed::ChN::<24>::MIN.into_inner(),
+    );
+    assert_eq!(
+        Conversion::<unsigned::Ch12, unsigned::Ch32>::convert(
+            unsigned::Ch12::new(4_000)
+        ),
+        Conversion::<unsigned::ChN<12>, unsigned::Ch32>::convert(
+            unsigned::ChN::new(4_000)
+        ),
+    );
+    assert_eq!(
+        Conversion::<signed::Ch64, signed::Ch24>::convert(signed::Ch64::new(
+            -0.25
+        ))
+        .into_inner(),
+        Conversion::<signed::Ch64, signed::ChN<24>>::convert(
+            signed::Ch64::new(-0.25)
+        )
+        .into_inner(),
+    );
+}
+
+#[test]
+fn convert_float() {
+    let convert = Conversion::<unsigned::Ch32, unsigned::ChN<11>>::convert;
+
+    assert_eq!(convert(unsigned::Ch32::new(0.0)).into_inner(), 0);
+    assert_eq!(convert(unsigned::Ch32::new(0.5)).into_inner(), 1_024);
+    assert_eq!(convert(unsigned::Ch32::new(2.0)).into_inner(), 2_047);
+
+    let convert = Conversion::<signed::Ch32, signed::ChN<11>>::convert;
+
+    assert_eq!(convert(signed::Ch32::new(-1.0)), signed::ChN::MIN);
+    assert_eq!(convert(signed::Ch32::new(0.0)).into_inner(), 0);
+    assert_eq!(convert(signed::Ch32::new(1.0)), signed::ChN::MAX);
+
+    for i in -1_024..1_024 {
+        let chan = signed::ChN::<11>::new(i);
+        let float = Conversion::<signed::ChN<11>, signed::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<signed::Ch64, signed::ChN<11>>::convert(float),
+            chan
+        );
+    }
+
+    assert_eq!(
+        Conversion::<unsigned::ChN<11>, unsigned::Ch64>::convert(
+            unsigned::ChN::MAX,
+        ),
+        unsigned::Ch64::MAX,
+    );
+}
@@ -0,0 +1,132 @@
+#![cfg(feature = "clip")]
+
+use core::f64::consts::TAU;
+
+use p_chan::{
+    clip::{Limiter, MAX_LOOKAHEAD, Shape},
+    gain::Gain,
+    ops::Clipping,
+    signed::{Ch32, Ch64},
+};
+
+#[test]
+fn detection() {
+    assert!(!Clipping(Ch32::new(1.0)).is_clipped());
+    assert!(!Clipping(Ch32::new(-1.0)).is_clipped());
+    assert!(Clipping(Ch32::new(1.000_001)).is_clipped());
+    assert!(Clipping(Ch64::new(-2.0)).is_clipped());
+    assert!(Clipping(Ch64::new(f64::INFINITY)).is_clipped());
+
+    let buffer = [0.0, 1.5, -1.0, -1.5, 0.99, 3.0].map(Ch64::new);
+
+    assert_eq!(Clipping(&buffer[..]).count(), 3);
+    assert_eq!(Clipping(&[][..] as &[Ch32]).count(), 0);
+}
+
+#[test]
+fn soft_clip() {
+    for shape in [Shape::Tanh, Shape::Cubic, Shape::Sine] {
+        let clip = |x: f64| Clipping(Ch64::new(x)).soft_clip(shape);
+
+        assert_eq!(clip(0.0), Ch64::new(0.0));
+
+        // Odd symmetric, monotonic, and never clipping
+        let mut last = clip(-10.0);
+
+        for i in -1_000..=1_000 {
+            let x = f64::from(i) / 100.0;
+            let y = clip(x);
+
+            assert_eq!(clip(-x), Ch64::new(-y.into_inner()));
+            assert!(y >= last);
+            assert!(!Clipping(y).is_clipped());
+            last = y;
+        }
+    }
+
+    let clip = |shape, x: f64| Clipping(Ch64::new(x)).soft_clip(shape);
+
+    assert!(
+        (clip(Shape::Tanh, 0.5).into_inner() - 0.5_f64.tanh()).abs() < 1e-15
+    );
+    assert!(
+        (clip(Shape::Tanh, 4.0).into_inner() - 4.0_f64.tanh()).abs() < 1e-15
+    );
+    assert_eq!(clip(Shape::Cubic, 0.5), Ch64::new(0.6875));
+    assert_eq!(clip(Shape::Cubic, 1.0), Ch64::MAX);
+    assert_eq!(clip(Shape::Cubic, -7.0), Ch64::MIN);
+    assert!((clip(Shape::Sine, 1.0 / 3.0).into_inner() - 0.5).abs() < 1e-15);
+    assert_eq!(clip(Shape::Sine, 1.5), Ch64::MAX);
+
+    let mut buffer = [0.25, 2.0, -2.0].map(Ch32::new);
+
+    Clipping(&mut buffer[..]).soft_clip(Shape::Sine);
+    assert!((buffer[0].into_inner() - 0.382_683_43).abs() < 1e-7);
+    assert_eq!(&buffer[1..], [Ch32::MAX, Ch32::MIN]);
+}
+
+#[test]
+fn limiter() {
+    let ceiling = Gain::from_db(-3.0);
+    let mut limiter = Limiter::<Ch64>::new(ceiling, 48, 240);
+    let mut buffer: [Ch64; 4_800] = core::array::from_fn(|i| {
+        // Loud burst in the middle of a quiet sine wave
+        let level = if (2_000..2_400).contains(&i) {
+            4.0
+        } else {
+            0.25
+        };
+
+        Ch64::new(level * (TAU * i as f64 / 100.0).sin())
+    });
+    let input = buffer;
+
+    assert_eq!(limiter.latency(), 48);
+    limiter.process(&mut buffer[..2_000]);
+    limiter.process(&mut buffer[2_000..]);
+
+    let ceiling = f64::from(ceiling.into_inner());
+
+    assert!(buffer.iter().all(|chan| chan.into_inner().abs() <= ceiling));
+
+    // Quiet signal is delayed, but not changed
+    for i in 48..1_900 {
+        assert_eq!(buffer[i], input[i - 48]);
+    }
+
+    // Gain has recovered after the release
+    assert!((limiter.gain().into_inner() - 1.0).abs() < 1e-3);
+    for i in 4_500..4_800 {
+        assert!(
+            (buffer[i].into_inner() - input[i - 48].into_inner()).abs() < 1e-3
+        );
+    }
+
+    limiter.reset();
+    assert_eq!(limiter.gain(), Gain::UNITY);
+}
+
+#[test]
+fn limiter_ramp() {
+    let mut limiter = Limiter::<Ch32>::new(Gain::UNITY, 4, 0);
+    let mut buffer = [Ch32::new(0.5); 12];
+
+    buffer[6] = Ch32::new(2.0);
+    limiter.process(&mut buffer);
+
+    // Gain ramps down over the lookahead before the peak, and (without
+    // release) back up over the lookahead after it
+    assert_eq!(
+        buffer.map(Ch32::into_inner),
+        [
+            0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.4375, 0.375, 0.3125, 0.25, 1.0,
+            0.3125
+        ],
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid lookahead")]
+fn limiter_lookahead() {
+    let _ = Limiter::<Ch32>::new(Gain::UNITY, MAX_LOOKAHEAD + 1, 0);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "composite")]
+
+use p_chan::{
+    composite::{Blend, Operator},
+    ops::{Composition, Premultiplication},
+    pixel::{Bgra, Rgba},
+    unsigned::{Ch8, Ch16, Ch32},
+};
+
+fn rgba(r: f32, g: f32, b: f32, a: f32) -> Rgba<Ch32> {
+    Rgba::new(Ch32::new(r), Ch32::new(g), Ch32::new(b), Ch32::new(a))
+}
+
+fn gray8(v: u8, a: u8) -> Rgba<Ch8> {
+    Rgba::new(Ch8::new(v), Ch8::new(v), Ch8::new(v), Ch8::new(a))
+}
+
+#[test]
+fn premultiply_rounding() {
+    for c in 0..=255u32 {
+        for a in 0..=255u32 {
+            let pixel = gray8(c as u8, a as u8);
+            let expected = ((c * a + 127) / 255) as u8;
+            let premultiplied = Premultiplication(pixel).premultiply();
+
+            assert_eq!(premultiplied, gray8(expected, a as u8), "{c} {a}");
+        }
+    }
+}
+
+#[test]
+fn unpremultiply() {
+    let pixel = Rgba::new(Ch16::new(1_000), Ch16::MIN, Ch16::MAX, Ch16::MAX);
+
+    assert_eq!(Premultiplication(pixel).unpremultiply(), pixel);
+    assert_eq!(
+        Premultiplication(gray8(100, 200)).unpremultiply(),
+        gray8(128, 200),
+    );
+    assert_eq!(
+        Premultiplication(gray8(100, 0)).unpremultiply(),
+        Rgba::<Ch8>::TRANSPARENT,
+    );
+    assert_eq!(
+        Premultiplication(rgba(0.25, 0.5, 0.0, 0.5)).unpremultiply(),
+        rgba(0.5, 1.0, 0.0, 0.5),
+    );
+}
+
+#[test]
+fn porter_duff() {
+    let source = rgba(0.5, 0.0, 0.0, 0.5);
+    let destination = rgba(0.0, 0.0, 0.25, 0.25);
+    let composite =
+        |operator| Composition(source, destination).composite(operator);
+
+    assert_eq!(composite(Operator::Clear), Rgba::<Ch32>::TRANSPARENT);
+    assert_eq!(composite(Operator::Src), source);
+    assert_eq!(composite(Operator::Dst), destination);
+    assert_eq!(composite(Operator::SrcOver), rgba(0.5, 0.0, 0.125, 0.625));
+    assert_eq!(composite(Operator::DstOver), rgba(0.375, 0.0, 0.25, 0.625));
+    assert_eq!(composite(Operator::SrcIn), rgba(0.125, 0.0, 0.0, 0.125));
+    assert_eq!(composite(Operator::DstIn), rgba(0.0, 0.0, 0.125, 0.125));
+    assert_eq!(composite(Operator::SrcOut), rgba(0.375, 0.0, 0.0, 0.375));
+    assert_eq!(composite(Operator::DstOut), rgba(0.0, 0.0, 0.125, 0.125));
+    assert_eq!(composite(Operator::SrcAtop), rgba(0.125, 0.0, 0.125, 0.25));
+    assert_eq!(composite(Operator::DstAtop), rgba(0.375, 0.0, 0.125, 0.5));
+    assert_eq!(composite(Operator::Xor), rgba(0.375, 0.0, 0.125, 0.5));
+    assert_eq!(composite(Operator::Plus), rgba(0.5, 0.0, 0.25, 0.75));
+}
+
+#[test]
+fn porter_duff_integer() {
+    let source = Premultiplication(gray8(255, 128)).premultiply();
+    let composited =
+        Composition(source, gray8(0, 255)).composite(Operator::SrcOver);
+
+    assert_eq!(composited, gray8(128, 255));
+    assert_eq!(
+        Composition(gray8(200, 200), gray8(200, 200)).composite(Operator::Plus),
+        gray8(255, 255),
+    );
+
+    let source = Bgra::new(Ch8::MIN, Ch8::MIN, Ch8::new(51), Ch8::new(51));
+
+    assert_eq!(
+        Composition(source, Bgra::<Ch8>::WHITE).composite(Operator::SrcOver),
+        Bgra::new(Ch8::new(204), Ch8::new(204), Ch8::MAX, Ch8::MAX),
+    );
+}
+
+#[test]
+fn blend_opaque() {
+    let backdrop = rgba(0.25, 0.5, 0.75, 1.0);
+    let source = rgba(0.5, 0.25, 1.0, 1.0);
+    let blend = |mode| Composition(source, backdrop).blend(mode);
+
+    assert_eq!(blend(Blend::Normal), source);
+    assert_eq!(blend(Blend::Multiply), rgba(0.125, 0.125, 0.75, 1.0));
+    assert_eq!(blend(Blend::Screen), rgba(0.625, 0.625, 1.0, 1.0));
+    assert_eq!(blend(Blend::Overlay), rgba(0.25, 0.25, 1.0, 1.0));
+    assert_eq!(blend(Blend::Darken), rgba(0.25, 0.25, 0.75, 1.0));
+    assert_eq!(blend(Blend::Lighten), rgba(0.5, 0.5, 1.0, 1.0));
+    assert_eq!(blend(Blend::ColorDodge), rgba(0.5, 0.666_666_7, 1.0, 1.0));
+    assert_eq!(blend(Blend::ColorBurn), rgba(0.0, 0.0, 0.75, 1.0));
+    assert_eq!(blend(Blend::HardLight), rgba(0.25, 0.25, 1.0, 1.0));
+    assert_eq!(blend(Blend::SoftLight), rgba(0.25, 0.375, 0.866_025_4, 1.0));
+    assert_eq!(blend(Blend::Difference), rgba(0.25, 0.25, 0.25, 1.0));
+    assert_eq!(blend(Blend::Exclusion), rgba(0.5, 0.5, 0.25, 1.0));
+}
+
+#[test]
+fn blend_transparent() {
+    let source = rgba(0.25, 0.25, 0.25, 0.5);
+    let backdrop = rgba(0.5, 0.5, 0.5, 0.5);
+
+    // Multiply with alpha is source over for uncovered parts
+    assert_eq!(
+        Composition(source, backdrop).blend(Blend::Multiply),
+        rgba(0.5, 0.5, 0.5, 0.75),
+    );
+    assert_eq!(
+        Composition(source, Rgba::<Ch32>::TRANSPARENT).blend(Blend::Difference),
+        source,
+    );
+}
+
+const _: Rgba<Ch8> =
+    Composition(Rgba::<Ch8>::BLACK, Rgba::<Ch8>::WHITE).blend(Blend::Screen);
@@ -0,0 +1,82 @@
+#![cfg(feature = "dsd")]
+
+use p_chan::{
+    dsd::{Ch1, Decimator, Modulator},
+    ops::Conversion,
+    signed::Ch32,
+};
+
+const RATIO: usize = 64;
+
+fn round_trip(input: &[Ch32]) -> Vec<f32> {
+    let mut modulator = Modulator::new(RATIO);
+    let mut decimator = Decimator::new(RATIO);
+    let mut stream = Vec::new();
+    let mut output = Vec::new();
+
+    stream.resize(input.len() * RATIO / 8, 0);
+    output.resize(input.len(), Ch32::new(0.0));
+
+    modulator.process(input, &mut stream);
+
+    let written = decimator.process(&stream, &mut output);
+
+    assert_eq!(written, input.len());
+    output.into_iter().map(Ch32::into_inner).collect()
+}
+
+#[test]
+fn pack_bits() {
+    let bits = Conversion::<u8, [Ch1; 8]>::convert(0x69);
+
+    assert_eq!(bits[0], Ch1::MIN);
+    assert_eq!(bits[1], Ch1::MAX);
+    assert_eq!(bits[7], Ch1::MAX);
+    assert_eq!(Conversion::<[Ch1; 8], u8>::convert(bits), 0x69);
+    assert_eq!(Conversion::<Ch1, Ch32>::convert(Ch1::MAX), Ch32::MAX);
+    assert_eq!(Conversion::<Ch1, Ch32>::convert(Ch1::MIN), Ch32::MIN);
+}
+
+#[test]
+fn silence() {
+    let mut decimator = Decimator::new(RATIO);
+    let mut output = [Ch32::new(1.0); 4];
+
+    assert_eq!(decimator.process(&[0x69; 32], &mut output), 4);
+
+    for chan in output {
+        assert!(chan.into_inner().abs() < 1e-6);
+    }
+}
+
+#[test]
+fn dc_round_trip() {
+    let output = round_trip(&[Ch32::new(0.25); 256]);
+
+    for &sample in &output[64..] {
+        assert!((sample - 0.25).abs() < 0.005, "{sample}");
+    }
+}
+
+#[test]
+fn sine_round_trip() {
+    let frequency = 1_000.0 / 44_100.0 * core::f64::consts::TAU;
+    let input: Vec<_> = (0..4_410)
+        .map(|i| Ch32::new((0.5 * (frequency * i as f64).sin()) as f32))
+        .collect();
+    let output = round_trip(&input);
+    // Skip the startup transient, and compensate for filter delay
+    let delay = 8;
+    let (mut error, mut power) = (0.0, 0.0);
+
+    for i in 256..input.len() - delay {
+        let expected = f64::from(input[i].into_inner());
+        let actual = f64::from(output[i + delay]);
+
+        error += (actual - expected).powi(2);
+        power += expected.powi(2);
+    }
+
+    // Better than 40 dB signal-to-noise (and delay) ratio
+    assert!(error / power < 1e-4, "{}", error / power);
+}
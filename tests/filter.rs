@@ -0,0 +1,236 @@
+#![cfg(feature = "filter")]
+
+use core::f64::consts::TAU;
+
+use p_chan::{
+    filter::{Biquad, Coefficients, OnePole},
+    gain::Gain,
+    signed::{Ch8, Ch16, Ch32, Ch64},
+};
+
+const RATE: u32 = 48_000;
+
+fn sine<const N: usize>(frequency: f64) -> [Ch64; N] {
+    core::array::from_fn(|i| {
+        Ch64::new((TAU * frequency * i as f64 / RATE as f64).sin())
+    })
+}
+
+/// Peak level of the second half of a buffer (after settling)
+fn peak(chans: &[Ch64]) -> f64 {
+    chans[chans.len() / 2..]
+        .iter()
+        .fold(0.0, |peak, chan| peak.max(chan.into_inner().abs()))
+}
+
+/// Measure the gain of a filter at a frequency.
+fn response(coefficients: Coefficients, frequency: f64) -> f64 {
+    let mut filter = Biquad::<Ch64>::new(coefficients);
+    let mut buffer = sine::<9_600>(frequency);
+
+    filter.process(&mut buffer);
+    peak(&buffer)
+}
+
+fn db(gain: f64) -> f64 {
+    20.0 * gain.log10()
+}
+
+#[test]
+fn identity() {
+    let mut filter = Biquad::<Ch16>::new(Coefficients::IDENTITY);
+    let mut buffer = [Ch16::new(-3), Ch16::MAX, Ch16::MIN, Ch16::new(7)];
+    let expected = buffer;
+
+    filter.process(&mut buffer);
+    assert_eq!(buffer, expected);
+    assert_eq!(
+        Coefficients::new([2.0, 0.0, 0.0], [2.0, 0.0, 0.0]),
+        Coefficients::IDENTITY,
+    );
+}
+
+#[test]
+fn low_pass() {
+    let coefficients = Coefficients::low_pass(RATE, 1_000.0, 0.707);
+
+    assert!((response(coefficients, 100.0) - 1.0).abs() < 1e-3);
+    assert!((db(response(coefficients, 1_000.0)) + 3.0).abs() < 0.05);
+    assert!(db(response(coefficients, 10_000.0)) < -39.0);
+
+    let magnitude = coefficients.magnitude(RATE, 10_000.0).into_inner();
+
+    assert!((magnitude as f64 - response(coefficients, 10_000.0)).abs() < 1e-3);
+}
+
+#[test]
+fn high_pass() {
+    let coefficients = Coefficients::high_pass(RATE, 1_000.0, 0.707);
+
+    assert!(db(response(coefficients, 100.0)) < -39.0);
+    assert!((db(response(coefficients, 1_000.0)) + 3.0).abs() < 0.05);
+    assert!((response(coefficients, 10_000.0) - 1.0).abs() < 1e-2);
+
+    // DC is removed
+    let mut filter = Biquad::<Ch32>::new(coefficients);
+    let mut buffer = [Ch32::new(0.5); 4_800];
+
+    filter.process(&mut buffer);
+    assert!(buffer[4_799].into_inner().abs() < 1e-6);
+}
+
+#[test]
+fn band_pass_notch() {
+    let band_pass = Coefficients::band_pass(RATE, 1_000.0, 2.0);
+    let notch = Coefficients::notch(RATE, 1_000.0, 2.0);
+
+    assert!((response(band_pass, 1_000.0) - 1.0).abs() < 1e-3);
+    assert!(response(band_pass, 100.0) < 0.1);
+    assert!(response(notch, 1_000.0) < 1e-3);
+    assert!((response(notch, 100.0) - 1.0).abs() < 1e-2);
+}
+
+#[test]
+fn all_pass() {
+    let coefficients = Coefficients::all_pass(RATE, 1_000.0, 0.707);
+
+    for frequency in [100.0, 1_000.0, 10_000.0] {
+        assert!((response(coefficients, frequency) - 1.0).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn peaking() {
+    let boost = Coefficients::peaking(RATE, 1_000.0, 1.0, Gain::from_db(6.0));
+    let cut = Coefficients::peaking(RATE, 1_000.0, 1.0, Gain::from_db(-6.0));
+
+    assert!((db(response(boost, 1_000.0)) - 6.0).abs() < 0.05);
+    assert!((db(response(cut, 1_000.0)) + 6.0).abs() < 0.05);
+    assert!(db(response(boost, 20.0)).abs() < 0.05);
+    assert!(db(response(boost, 20_000.0)).abs() < 0.05);
+}
+
+#[test]
+fn shelves() {
+    let gain = Gain::from_db(-12.0);
+    let low = Coefficients::low_shelf(RATE, 1_000.0, 0.707, gain);
+    let high = Coefficients::high_shelf(RATE, 1_000.0, 0.707, gain);
+
+    assert!((db(response(low, 20.0)) + 12.0).abs() < 0.05);
+    assert!(db(response(low, 20_000.0)).abs() < 0.05);
+    assert!(db(response(high, 20.0)).abs() < 0.05);
+    assert!((db(response(high, 20_000.0)) + 12.0).abs() < 0.05);
+
+    // Half of the gain at the corner frequency
+    let magnitude = low.magnitude(RATE, 1_000.0);
+
+    assert!((magnitude.to_db() + 6.0).abs() < 0.05);
+}
+
+#[test]
+fn one_pole() {
+    // DC blocker
+    let mut filter = OnePole::<Ch16>::high_pass(RATE, 20.0);
+    let mut buffer = [Ch16::new(-10_000); 48_000];
+
+    filter.process(&mut buffer);
+    assert!(buffer[0].into_inner() < -9_900);
+    assert_eq!(buffer[47_999], Ch16::new(0));
+
+    // Low-pass settles to DC, and attenuates high frequencies
+    let mut filter = OnePole::<Ch64>::low_pass(RATE, 100.0);
+    let mut buffer = [Ch64::new(0.25); 4_800];
+
+    filter.process(&mut buffer);
+    assert!((buffer[4_799].into_inner() - 0.25).abs() < 1e-9);
+
+    let mut filter = OnePole::<Ch64>::low_pass(RATE, 100.0);
+    let mut buffer = sine::<9_600>(10_000.0);
+
+    filter.process(&mut buffer);
+    assert!(peak(&buffer) < 0.02);
+}
+
+#[test]
+fn state() {
+    let coefficients = Coefficients::low_pass(RATE, 1_000.0, 0.707);
+    let mut whole = Biquad::<Ch64>::new(coefficients);
+    let mut split = Biquad::<Ch64>::new(coefficients);
+    let mut a = sine::<1_000>(440.0);
+    let mut b = a;
+
+    // State persists across buffers
+    whole.process(&mut a);
+    split.process(&mut b[..300]);
+    split.process(&mut b[300..]);
+    assert_eq!(a, b);
+
+    // Changing coefficients keeps the state
+    split.set_coefficients(Coefficients::IDENTITY);
+    assert_eq!(split.coefficients(), Coefficients::IDENTITY);
+
+    let mut c = [Ch64::new(0.0)];
+
+    split.process(&mut c);
+    assert_ne!(c[0], Ch64::new(0.0));
+
+    split.reset();
+
+    let mut c = [Ch64::new(0.0)];
+
+    split.process(&mut c);
+    assert_eq!(c[0], Ch64::new(0.0));
+}
+
+#[test]
+fn saturate() {
+    let boost = Coefficients::peaking(RATE, 1_000.0, 1.0, Gain::from_db(12.0));
+    let mut filter = Biquad::<Ch8>::new(boost);
+    let mut buffer: [Ch8; 480] = core::array::from_fn(|i| {
+        Ch8::new((100.0 * (TAU * i as f64 / 48.0).sin()).round() as i8)
+    });
+
+    filter.process(&mut buffer);
+    assert!(buffer.contains(&Ch8::MAX));
+    assert!(buffer.contains(&Ch8::MIN));
+}
+
+#[test]
+fn unsigned() {
+    use p_chan::unsigned;
+
+    // DC is removed around the midpoint
+    let mut filter = OnePole::<unsigned::Ch8>::high_pass(RATE, 20.0);
+    let mut buffer = [unsigned::Ch8::new(200); 48_000];
+
+    filter.process(&mut buffer);
+    assert!(buffer[0].into_inner() > 198);
+    assert_eq!(buffer[47_999], unsigned::Ch8::new(128));
+
+    let mut filter = Biquad::<unsigned::Ch16>::new(Coefficients::high_pass(
+        RATE, 20.0, 0.707,
+    ));
+    let mut buffer = [unsigned::Ch16::new(1_000); 48_000];
+
+    filter.process(&mut buffer);
+    assert_eq!(buffer[47_999], unsigned::Ch16::new(32_768));
+
+    // Saturates at both ends
+    let boost = Coefficients::peaking(RATE, 1_000.0, 1.0, Gain::from_db(12.0));
+    let mut filter = Biquad::<unsigned::Ch8>::new(boost);
+    let mut buffer: [unsigned::Ch8; 480] = core::array::from_fn(|i| {
+        let sample = 128.0 + 100.0 * (TAU * i as f64 / 48.0).sin();
+
+        unsigned::Ch8::new(sample.round() as u8)
+    });
+
+    filter.process(&mut buffer);
+    assert!(buffer.contains(&unsigned::Ch8::MAX));
+    assert!(buffer.contains(&unsigned::Ch8::MIN));
+}
+
+#[test]
+#[should_panic(expected = "invalid frequency")]
+fn invalid_frequency() {
+    Coefficients::low_pass(RATE, 24_000.0, 0.707);
+}
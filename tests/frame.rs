@@ -0,0 +1,165 @@
+#![cfg(all(feature = "frame", feature = "unsigned"))]
+
+use p_chan::{
+    frame::{self, ChannelLayout, Frame, Speaker},
+    ops::Conversion,
+    signed, unsigned,
+};
+
+#[test]
+fn layout_channels() {
+    assert_eq!(ChannelLayout::MONO.channels(), 1);
+    assert_eq!(ChannelLayout::STEREO.channels(), 2);
+    assert_eq!(ChannelLayout::SURROUND_5_1.channels(), 6);
+    assert_eq!(ChannelLayout::SURROUND_7_1.channels(), 8);
+    assert_eq!(ChannelLayout::Ambisonic(0).channels(), 1);
+    assert_eq!(ChannelLayout::Ambisonic(1).channels(), 4);
+    assert_eq!(ChannelLayout::Ambisonic(3).channels(), 16);
+    // Unknown bits are ignored
+    assert_eq!(ChannelLayout::Speakers(0x8000_0003).channels(), 2);
+}
+
+#[test]
+fn layout_masks() {
+    assert_eq!(ChannelLayout::STEREO, ChannelLayout::Speakers(0x3));
+    assert_eq!(ChannelLayout::SURROUND_5_1, ChannelLayout::Speakers(0x60f));
+    assert_eq!(
+        ChannelLayout::SURROUND_5_1_BACK,
+        ChannelLayout::Speakers(0x3f)
+    );
+    assert_eq!(ChannelLayout::SURROUND_7_1, ChannelLayout::Speakers(0x63f));
+}
+
+#[test]
+fn layout_order() {
+    let layout = ChannelLayout::SURROUND_7_1;
+
+    for (i, speaker) in [
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::LowFrequency,
+        Speaker::BackLeft,
+        Speaker::BackRight,
+        Speaker::SideLeft,
+        Speaker::SideRight,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        assert_eq!(layout.index(speaker), Some(i));
+        assert_eq!(layout.speaker(i), Some(speaker));
+    }
+
+    assert_eq!(layout.speaker(8), None);
+    assert_eq!(layout.index(Speaker::TopCenter), None);
+    assert!(!layout.contains(Speaker::BackCenter));
+    assert_eq!(ChannelLayout::Ambisonic(1).speaker(0), None);
+}
+
+#[test]
+fn convert() {
+    let frame = Frame([signed::Ch16::MAX, signed::Ch16::new(0)]);
+    let frame = frame.map(Conversion::<signed::Ch16, signed::Ch32>::convert);
+
+    assert_eq!(
+        frame.into_inner(),
+        [signed::Ch32::MAX, signed::Ch32::new(1.0 / 65_535.0)]
+    );
+}
+
+#[test]
+fn mix() {
+    let a = Frame([signed::Ch16::new(10_000), signed::Ch16::new(-20_000)]);
+    let b = Frame([signed::Ch16::new(30_000), signed::Ch16::new(-20_000)]);
+
+    // Saturating
+    assert_eq!(
+        a + b,
+        Frame([signed::Ch16::MAX, signed::Ch16::new(-32_768)])
+    );
+    assert_eq!(
+        b - a,
+        Frame([signed::Ch16::new(20_000), signed::Ch16::new(0)])
+    );
+    assert_eq!(
+        a.midpoint(b),
+        Frame([signed::Ch16::new(20_000), signed::Ch16::new(-20_000)]),
+    );
+
+    let a = Frame([unsigned::Ch32::new(0.25); 6]);
+
+    assert_eq!(
+        (a + a).clamp(
+            Frame([unsigned::Ch32::MIN; 6]),
+            Frame([unsigned::Ch32::new(0.4); 6])
+        ),
+        Frame([unsigned::Ch32::new(0.4); 6]),
+    );
+    assert_eq!(
+        Frame::<unsigned::Ch8, 2>::default(),
+        Frame([unsigned::Ch8::MIN; 2])
+    );
+}
+
+#[test]
+fn interleave_round_trip() {
+    use signed::{Ch16, Ch32};
+
+    let interleaved = [1, 2, 3, 4, 5, 6].map(Ch16::new);
+    let (mut a, mut b, mut c) = (
+        [Ch32::new(0.0); 2],
+        [Ch32::new(0.0); 2],
+        [Ch32::new(0.0); 2],
+    );
+
+    frame::deinterleave(
+        &interleaved,
+        &mut [&mut a, &mut b, &mut c],
+        Conversion::<Ch16, Ch32>::convert,
+    );
+
+    assert_eq!(
+        a,
+        [1, 4].map(|x| Conversion::<Ch16, Ch32>::convert(Ch16::new(x)))
+    );
+    assert_eq!(
+        c,
+        [3, 6].map(|x| Conversion::<Ch16, Ch32>::convert(Ch16::new(x)))
+    );
+
+    let mut output = [Ch16::new(0); 6];
+
+    frame::interleave(
+        &[&a, &b, &c],
+        &mut output,
+        Conversion::<Ch32, Ch16>::convert,
+    );
+
+    assert_eq!(output, interleaved);
+
+    let mut copy = [Ch16::new(0); 6];
+    let planar = [[1, 3, 5].map(Ch16::new), [2, 4, 6].map(Ch16::new)];
+
+    frame::interleave(
+        &[&planar[0], &planar[1]],
+        &mut copy,
+        core::convert::identity,
+    );
+
+    assert_eq!(copy, interleaved);
+}
+
+#[test]
+#[should_panic(expected = "buffer length mismatch")]
+fn interleave_mismatch() {
+    use signed::Ch16;
+
+    let mut output = [Ch16::new(0); 5];
+
+    frame::interleave(
+        &[&[Ch16::new(0); 3], &[Ch16::new(0); 3]],
+        &mut output,
+        core::convert::identity,
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(all(feature = "gain", feature = "unsigned"))]
+
+use p_chan::{
+    gain::{Gain, Ramp},
+    ops::Amplification,
+    signed, unsigned,
+};
+
+#[test]
+fn decibels() {
+    assert_eq!(Gain::from_db(0.0), Gain::UNITY);
+    assert_eq!(Gain::from_db(20.0), Gain::new(10.0));
+    assert_eq!(Gain::from_db(-40.0), Gain::new(0.01));
+    assert_eq!(Gain::from_db(f32::NEG_INFINITY), Gain::SILENCE);
+    assert!((Gain::from_db(-6.0).into_inner() - 0.501_187_2).abs() < 1e-7);
+    assert!((Gain::from_db(6.020_6).into_inner() - 2.0).abs() < 1e-5);
+    assert_eq!(Gain::new(10.0).to_db(), 20.0);
+    assert_eq!(Gain::new(0.1).to_db(), -20.0);
+    assert_eq!(Gain::SILENCE.to_db(), f32::NEG_INFINITY);
+
+    for db in [-96.0, -12.5, -0.1, 3.0, 24.0] {
+        assert!((Gain::from_db(db).to_db() - db).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn saturation() {
+    use signed::{Ch8, Ch24};
+
+    assert_eq!(
+        Amplification(Ch8::new(100)).amplify(Gain::new(2.0)),
+        Ch8::MAX
+    );
+    assert_eq!(
+        Amplification(Ch8::new(-100)).amplify(Gain::new(2.0)),
+        Ch8::MIN,
+    );
+    assert_eq!(
+        Amplification(Ch8::new(-100)).amplify(Gain::new(-1.0)),
+        Ch8::new(100),
+    );
+    assert_eq!(
+        Amplification(Ch24::new(1_000_001)).amplify(Gain::new(0.5)),
+        Ch24::new(500_001),
+    );
+
+    // Around the middle for unsigned
+    assert_eq!(
+        Amplification(unsigned::Ch8::new(228)).amplify(Gain::new(0.5)),
+        unsigned::Ch8::new(178),
+    );
+    assert_eq!(
+        Amplification(unsigned::Ch8::new(228)).amplify(Gain::new(2.0)),
+        unsigned::Ch8::MAX,
+    );
+    assert_eq!(
+        Amplification(unsigned::Ch32::new(0.0)).amplify(Gain::new(0.5)),
+        unsigned::Ch32::new(0.25),
+    );
+
+    // Floating-point isn't clipped
+    assert_eq!(
+        Amplification(signed::Ch32::new(0.75)).amplify(Gain::new(2.0)),
+        signed::Ch32::new(1.5),
+    );
+}
+
+#[test]
+fn slice() {
+    use signed::Ch16;
+
+    let mut buffer = [100, -200, 300].map(Ch16::new);
+
+    Amplification(&mut buffer[..]).amplify(Gain::new(-0.5));
+
+    assert_eq!(buffer, [-50, 100, -150].map(Ch16::new));
+}
+
+#[test]
+fn ramp() {
+    use signed::Ch16;
+
+    let mut ramp = Ramp::new(Gain::SILENCE);
+    // Stereo
+    let mut buffer = [Ch16::new(1_000); 10];
+
+    ramp.set(Gain::UNITY, 4);
+    assert!(!ramp.is_done());
+    Amplification(&mut buffer[..]).ramp(&mut ramp, 2);
+
+    assert_eq!(
+        buffer.map(Ch16::into_inner),
+        [250, 250, 500, 500, 750, 750, 1_000, 1_000, 1_000, 1_000],
+    );
+    assert!(ramp.is_done());
+    assert_eq!(ramp.gain(), Gain::UNITY);
+
+    // Retargeting mid-ramp continues from the current gain
+    ramp.set(Gain::new(0.0), 4);
+    Amplification(&mut buffer[..2]).ramp(&mut ramp, 1);
+    ramp.set(Gain::new(1.0), 2);
+    assert_eq!(ramp.gain(), Gain::new(0.5));
+    assert_eq!(ramp.target(), Gain::UNITY);
+
+    // Immediate
+    ramp.set(Gain::new(0.25), 0);
+    assert_eq!(ramp.gain(), Gain::new(0.25));
+    assert!(ramp.is_done());
+}
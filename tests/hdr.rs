@@ -0,0 +1,127 @@
+#![cfg(feature = "hdr")]
+
+use p_chan::{
+    hdr::{hlg, pq},
+    ops::Conversion,
+    unsigned,
+};
+
+#[test]
+fn pq_reference() {
+    let encode_10 = |luminance| {
+        Conversion::<unsigned::Ch64, pq::Ch10>::convert(unsigned::Ch64::new(
+            luminance,
+        ))
+        .into_inner()
+    };
+    let encode_12 = |luminance| {
+        Conversion::<unsigned::Ch64, pq::Ch12>::convert(unsigned::Ch64::new(
+            luminance,
+        ))
+        .into_inner()
+    };
+
+    // 0, 1, 100, 1_000 and 10_000 cd/m²
+    assert_eq!(encode_10(0.0), 0);
+    assert_eq!(encode_10(0.000_1), 153);
+    assert_eq!(encode_10(0.01), 520);
+    assert_eq!(encode_10(0.1), 769);
+    assert_eq!(encode_10(1.0), 1_023);
+    assert_eq!(encode_12(0.0), 0);
+    assert_eq!(encode_12(0.000_1), 614);
+    assert_eq!(encode_12(0.01), 2_081);
+    assert_eq!(encode_12(0.1), 3_079);
+    assert_eq!(encode_12(1.0), 4_095);
+    // Out of range
+    assert_eq!(encode_10(2.0), 1_023);
+    assert_eq!(encode_10(-1.0), 0);
+
+    let decode = |code| {
+        Conversion::<pq::Ch10, unsigned::Ch64>::convert(pq::Ch10::new(code))
+            .into_inner()
+    };
+
+    assert_eq!(decode(0), 0.0);
+    assert!((decode(520) - 0.010_022_988_553_117_673).abs() < 1e-12);
+    assert!((decode(769) - 0.099_893_239_104_503_83).abs() < 1e-12);
+    assert!((decode(1_023) - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn hlg_reference() {
+    let encode_10 = |luminance| {
+        Conversion::<unsigned::Ch32, hlg::Ch10>::convert(unsigned::Ch32::new(
+            luminance,
+        ))
+        .into_inner()
+    };
+    let encode_12 = |luminance| {
+        Conversion::<unsigned::Ch32, hlg::Ch12>::convert(unsigned::Ch32::new(
+            luminance,
+        ))
+        .into_inner()
+    };
+
+    assert_eq!(encode_10(0.0), 0);
+    assert_eq!(encode_10(0.18), 688);
+    assert_eq!(encode_10(0.5), 892);
+    assert_eq!(encode_10(1.0), 1_023);
+    assert_eq!(encode_12(0.0), 0);
+    assert_eq!(encode_12(0.18), 2_753);
+    assert_eq!(encode_12(1.0), 4_095);
+
+    let decode = |code| {
+        Conversion::<hlg::Ch12, unsigned::Ch64>::convert(hlg::Ch12::new(code))
+            .into_inner()
+    };
+
+    // Signal of 0.5 is 1/12 scene luminance
+    assert!((decode(4_095) - 1.0).abs() < 1e-7);
+    assert!((decode(0) - 0.0).abs() < 1e-12);
+    assert!(
+        (Conversion::<hlg::Ch10, unsigned::Ch64>::convert(hlg::Ch10::new(512))
+            .into_inner()
+            - 0.083_496_475_530_246_15)
+            .abs()
+            < 1e-12,
+    );
+}
+
+#[test]
+fn round_trip() {
+    for code in 0..=1_023 {
+        let chan = pq::Ch10::new(code);
+        let linear = Conversion::<pq::Ch10, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, pq::Ch10>::convert(linear),
+            chan
+        );
+
+        let chan = hlg::Ch10::new(code);
+        let linear = Conversion::<hlg::Ch10, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, hlg::Ch10>::convert(linear),
+            chan,
+        );
+    }
+
+    for code in 0..=4_095 {
+        let chan = pq::Ch12::new(code);
+        let linear = Conversion::<pq::Ch12, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, pq::Ch12>::convert(linear),
+            chan
+        );
+
+        let chan = hlg::Ch12::new(code);
+        let linear = Conversion::<hlg::Ch12, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, hlg::Ch12>::convert(linear),
+            chan,
+        );
+    }
+}
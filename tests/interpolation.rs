@@ -0,0 +1,235 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
+use p_chan::{
+    ops::{Average, Interpolation},
+    signed, unsigned,
+};
+
+#[test]
+fn lerp_unsigned() {
+    use unsigned::{Ch8, Ch16, Ch32};
+
+    type Lerp = Interpolation<Ch8, Ch8>;
+
+    assert_eq!(
+        Lerp::lerp(Ch8::new(10), Ch8::new(200), Ch8::MIN),
+        Ch8::new(10)
+    );
+    assert_eq!(
+        Lerp::lerp(Ch8::new(10), Ch8::new(200), Ch8::MAX),
+        Ch8::new(200),
+    );
+    // 100 * 128 / 255 = 50.196
+    assert_eq!(Lerp::lerp(Ch8::MIN, Ch8::new(100), Ch8::MID), Ch8::new(50));
+    // 255 * 1 / 2 = 127.5 (ties away from zero)
+    assert_eq!(
+        Interpolation::<Ch8, Ch32>::lerp(Ch8::MIN, Ch8::MAX, Ch32::new(0.5)),
+        Ch8::new(128),
+    );
+    assert_eq!(
+        Interpolation::<Ch8, Ch32>::lerp(Ch8::MAX, Ch8::MIN, Ch32::new(0.5)),
+        Ch8::new(128),
+    );
+    // Decreasing
+    assert_eq!(
+        Interpolation::<Ch16, Ch8>::lerp(Ch16::MAX, Ch16::MIN, Ch8::new(51)),
+        Ch16::new(52_428),
+    );
+    assert_eq!(
+        Interpolation::<Ch32, Ch32>::lerp(
+            Ch32::new(0.25),
+            Ch32::new(0.75),
+            Ch32::new(0.5),
+        ),
+        Ch32::new(0.5),
+    );
+    // Out of range factors are clamped
+    assert_eq!(
+        Interpolation::<Ch32, Ch32>::lerp(
+            Ch32::new(0.25),
+            Ch32::new(0.75),
+            Ch32::new(2.0),
+        ),
+        Ch32::new(0.75),
+    );
+}
+
+#[test]
+fn lerp_signed() {
+    use signed::{Ch16, Ch24, Ch64};
+    use unsigned::{Ch8 as Alpha, Ch32 as Factor};
+
+    assert_eq!(
+        Interpolation::<Ch16, Alpha>::lerp(Ch16::MIN, Ch16::MAX, Alpha::MAX),
+        Ch16::MAX,
+    );
+    assert_eq!(
+        Interpolation::<Ch16, Factor>::lerp(
+            Ch16::new(-100),
+            Ch16::new(100),
+            Factor::new(0.25),
+        ),
+        Ch16::new(-50),
+    );
+    // -101 + 0.5 = -100.5 (ties away from zero)
+    assert_eq!(
+        Interpolation::<Ch24, Factor>::lerp(
+            Ch24::new(-101),
+            Ch24::new(-100),
+            Factor::new(0.5),
+        ),
+        Ch24::new(-101),
+    );
+    assert_eq!(
+        Interpolation::<Ch64, Alpha>::lerp(
+            Ch64::new(-1.0),
+            Ch64::new(1.0),
+            Alpha::new(51),
+        ),
+        Ch64::new(-0.6),
+    );
+
+    // Every factor is exact at the ends, and monotonic
+    let mut last = Ch16::MIN;
+
+    for t in 0..=255 {
+        let value = Interpolation::<Ch16, Alpha>::lerp(
+            Ch16::MIN,
+            Ch16::MAX,
+            Alpha::new(t),
+        );
+
+        assert!(value >= last);
+        last = value;
+    }
+
+    assert_eq!(last, Ch16::MAX);
+}
+
+#[test]
+fn inverse_lerp() {
+    use unsigned::{Ch8, Ch16, Ch64};
+
+    assert_eq!(
+        Interpolation::<Ch8, Ch16>::inverse_lerp(
+            Ch8::new(10),
+            Ch8::new(20),
+            Ch8::new(15),
+        ),
+        Ch16::new(32_768),
+    );
+    assert_eq!(
+        Interpolation::<Ch8, Ch64>::inverse_lerp(
+            Ch8::new(20),
+            Ch8::new(10),
+            Ch8::new(18),
+        ),
+        Ch64::new(0.2),
+    );
+    // Clamped
+    assert_eq!(
+        Interpolation::<Ch8, Ch8>::inverse_lerp(
+            Ch8::new(10),
+            Ch8::new(20),
+            Ch8::new(30),
+        ),
+        Ch8::MAX,
+    );
+    // Empty range
+    assert_eq!(
+        Interpolation::<Ch8, Ch8>::inverse_lerp(
+            Ch8::new(10),
+            Ch8::new(10),
+            Ch8::new(30),
+        ),
+        Ch8::MIN,
+    );
+
+    // Round trip
+    let (a, b) = (signed::Ch32::new(-0.5), signed::Ch32::new(0.5));
+
+    for value in [-0.5, -0.25, 0.0, 0.125, 0.5] {
+        let value = signed::Ch32::new(value);
+        let t = Interpolation::<signed::Ch32, unsigned::Ch32>::inverse_lerp(
+            a, b, value,
+        );
+
+        assert_eq!(
+            Interpolation::<signed::Ch32, unsigned::Ch32>::lerp(a, b, t),
+            value,
+        );
+    }
+}
+
+#[test]
+fn smoothstep() {
+    use signed::Ch16;
+    use unsigned::{Ch8, Ch64};
+
+    type Smooth = Interpolation<Ch16, Ch64>;
+
+    let (a, b) = (Ch16::new(-1_000), Ch16::new(1_000));
+
+    assert_eq!(Smooth::smoothstep(a, b, Ch16::MIN), Ch64::MIN);
+    assert_eq!(Smooth::smoothstep(a, b, a), Ch64::MIN);
+    assert_eq!(Smooth::smoothstep(a, b, Ch16::new(0)), Ch64::new(0.5));
+    assert_eq!(Smooth::smoothstep(a, b, Ch16::new(500)), Ch64::new(0.84375));
+    assert_eq!(Smooth::smoothstep(a, b, b), Ch64::MAX);
+    assert_eq!(Smooth::smoothstep(a, b, Ch16::MAX), Ch64::MAX);
+    // Step
+    assert_eq!(Smooth::smoothstep(a, a, Ch16::new(-1_001)), Ch64::MIN);
+    assert_eq!(Smooth::smoothstep(a, a, a), Ch64::MAX);
+    assert_eq!(
+        Interpolation::<Ch16, Ch8>::smoothstep(a, b, Ch16::new(-500)),
+        Ch8::new(40),
+    );
+}
+
+#[test]
+fn average() {
+    use unsigned::{Ch8, Ch32};
+
+    assert_eq!(
+        Average([Ch8::new(10), Ch8::new(20)], [Ch8::MAX, Ch8::MAX]).mean(),
+        Ch8::new(15),
+    );
+    assert_eq!(
+        Average(
+            [Ch8::new(0), Ch8::new(100), Ch8::new(255)],
+            [Ch32::new(0.5), Ch32::new(0.25), Ch32::new(0.25)],
+        )
+        .mean(),
+        Ch8::new(89),
+    );
+    assert_eq!(
+        Average(
+            [signed::Ch32::new(-1.0), signed::Ch32::new(0.5)],
+            [Ch8::new(1), Ch8::new(2)],
+        )
+        .mean(),
+        signed::Ch32::new(0.0),
+    );
+    // Zero weights are ignored
+    assert_eq!(
+        Average(
+            [signed::Ch8::MIN, signed::Ch8::new(-3)],
+            [Ch8::new(0), Ch8::new(7)],
+        )
+        .mean(),
+        signed::Ch8::new(-3),
+    );
+
+    const AVERAGE: Ch8 =
+        Average([Ch8::new(1), Ch8::new(2)], [Ch8::new(1), Ch8::new(1)]).mean();
+
+    // 1.5 (ties away from zero)
+    assert_eq!(AVERAGE, Ch8::new(2));
+}
+
+#[test]
+#[should_panic(expected = "zero total weight")]
+fn average_zero_weight() {
+    use unsigned::Ch8;
+
+    let _ = Average([Ch8::new(1)], [Ch8::MIN]).mean();
+}
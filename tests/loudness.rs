@@ -0,0 +1,187 @@
+#![cfg(feature = "loudness")]
+
+use core::f64::consts::TAU;
+
+use p_chan::{
+    frame::{ChannelLayout, Frame, Speaker},
+    loudness::Loudness,
+    signed::{Ch16, Ch32},
+};
+
+const RATE: u32 = 48_000;
+
+/// Feed a 1 kHz sine wave to each channel (at a level in dBFS, or silent for
+/// `None`) for a number of seconds.
+fn sine<const N: usize>(
+    meter: &mut Loudness<Ch32>,
+    levels: [Option<f64>; N],
+    seconds: f64,
+) {
+    let frames = (seconds * f64::from(RATE)) as usize;
+    let amplitudes =
+        levels.map(|level| level.map_or(0.0, |db| 10.0f64.powf(db / 20.0)));
+
+    for i in 0..frames {
+        let sample = (TAU * 1_000.0 * i as f64 / f64::from(RATE)).sin();
+        let frame =
+            amplitudes.map(|amplitude| Ch32::new((amplitude * sample) as f32));
+
+        meter.process(&[Frame(frame)]);
+    }
+}
+
+fn stereo() -> Loudness<Ch32> {
+    Loudness::new(ChannelLayout::STEREO, RATE).unwrap()
+}
+
+fn assert_close(actual: f32, expected: f32, tolerance: f32) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "{actual} not within {tolerance} of {expected}",
+    );
+}
+
+#[test]
+fn silence() {
+    let mut meter = stereo();
+
+    assert_eq!(meter.momentary(), f32::NEG_INFINITY);
+    assert_eq!(meter.integrated(), f32::NEG_INFINITY);
+    assert_eq!(meter.range(), 0.0);
+
+    sine(&mut meter, [None; 2], 4.0);
+    assert_eq!(meter.short_term(), f32::NEG_INFINITY);
+    assert_eq!(meter.integrated(), f32::NEG_INFINITY);
+    assert_eq!(meter.range(), 0.0);
+}
+
+#[test]
+fn ambisonic() {
+    assert!(Loudness::<Ch32>::new(ChannelLayout::Ambisonic(1), RATE).is_none());
+}
+
+#[test]
+#[should_panic(expected = "channel count mismatch")]
+fn channel_mismatch() {
+    stereo().process(&[Frame([Ch32::new(0.0); 3])]);
+}
+
+// EBU Tech 3341 test cases 1 and 2
+#[test]
+fn constant() {
+    for level in [-23.0, -33.0] {
+        let mut meter = stereo();
+
+        sine(&mut meter, [Some(level); 2], 20.0);
+        assert_close(meter.momentary(), level as f32, 0.1);
+        assert_close(meter.short_term(), level as f32, 0.1);
+        assert_close(meter.integrated(), level as f32, 0.1);
+    }
+}
+
+// EBU Tech 3341 test cases 3, 4, and 5 (gating)
+#[test]
+fn gating() {
+    let mut meter = stereo();
+
+    sine(&mut meter, [Some(-36.0); 2], 10.0);
+    sine(&mut meter, [Some(-23.0); 2], 60.0);
+    sine(&mut meter, [Some(-36.0); 2], 10.0);
+    assert_close(meter.integrated(), -23.0, 0.1);
+
+    let mut meter = stereo();
+
+    sine(&mut meter, [Some(-72.0); 2], 10.0);
+    sine(&mut meter, [Some(-36.0); 2], 10.0);
+    sine(&mut meter, [Some(-23.0); 2], 60.0);
+    sine(&mut meter, [Some(-36.0); 2], 10.0);
+    sine(&mut meter, [Some(-72.0); 2], 10.0);
+    assert_close(meter.integrated(), -23.0, 0.1);
+
+    let mut meter = stereo();
+
+    sine(&mut meter, [Some(-26.0); 2], 20.0);
+    sine(&mut meter, [Some(-20.0); 2], 20.1);
+    sine(&mut meter, [Some(-26.0); 2], 20.0);
+    assert_close(meter.integrated(), -23.0, 0.1);
+}
+
+// EBU Tech 3341 test case 6 (channel weighting)
+#[test]
+fn surround() {
+    let layout = ChannelLayout::speakers(&[
+        Speaker::FrontLeft,
+        Speaker::FrontRight,
+        Speaker::FrontCenter,
+        Speaker::SideLeft,
+        Speaker::SideRight,
+    ]);
+    let mut meter = Loudness::<Ch32>::new(layout, RATE).unwrap();
+    let (front, center, side) = (Some(-28.0), Some(-24.0), Some(-30.0));
+
+    sine(&mut meter, [front, front, center, side, side], 20.0);
+    assert_close(meter.integrated(), -23.0, 0.1);
+
+    // Low frequency effects channel is ignored
+    let mut meter =
+        Loudness::<Ch32>::new(ChannelLayout::SURROUND_5_1, RATE).unwrap();
+
+    sine(
+        &mut meter,
+        [front, front, center, Some(0.0), side, side],
+        20.0,
+    );
+    assert_close(meter.integrated(), -23.0, 0.1);
+}
+
+// EBU Tech 3342 test cases 1 to 4
+#[test]
+fn range() {
+    for (levels, expected) in [
+        (&[-20.0, -30.0][..], 10.0),
+        (&[-20.0, -15.0][..], 5.0),
+        (&[-40.0, -20.0][..], 20.0),
+        (&[-50.0, -35.0, -20.0, -35.0, -50.0][..], 15.0),
+    ] {
+        let mut meter = stereo();
+
+        for &level in levels {
+            sine(&mut meter, [Some(level); 2], 20.0);
+        }
+
+        assert_close(meter.range(), expected, 1.0);
+    }
+}
+
+#[test]
+fn windows() {
+    let mut meter = stereo();
+
+    sine(&mut meter, [Some(-20.0); 2], 5.0);
+    sine(&mut meter, [Some(-30.0); 2], 1.0);
+
+    // Momentary loudness has settled, but short-term loudness hasn't
+    assert_close(meter.momentary(), -30.0, 0.1);
+    assert!(meter.short_term() > -26.0);
+
+    meter.reset();
+    assert_eq!(meter.momentary(), f32::NEG_INFINITY);
+    assert_eq!(meter.integrated(), f32::NEG_INFINITY);
+}
+
+#[test]
+fn integer() {
+    let mut meter = Loudness::<Ch16>::new(ChannelLayout::MONO, RATE).unwrap();
+    let amplitude = 32_768.0 * 10.0f64.powf(-20.0 / 20.0);
+
+    for i in 0..RATE {
+        let sample = (TAU * 1_000.0 * f64::from(i) / f64::from(RATE)).sin();
+
+        meter.process(&[Frame([Ch16::new(
+            (amplitude * sample).round() as i16
+        )])]);
+    }
+
+    // Mono is 3 dB quieter than the same level on both stereo channels
+    assert_close(meter.integrated(), -23.0, 0.1);
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "meter")]
+
+use core::f64::consts::PI;
+
+use p_chan::{
+    meter::{Peak, Rms, TruePeak},
+    signed::{Ch8, Ch16, Ch24, Ch32, Ch64},
+};
+
+fn sine<const N: usize>(
+    frequency: f64,
+    phase: f64,
+    amplitude: f64,
+) -> [f64; N] {
+    core::array::from_fn(|i| {
+        amplitude * (2.0 * PI * frequency * i as f64 + phase).sin()
+    })
+}
+
+#[test]
+fn peak() {
+    let mut peak = Peak::<Ch16>::new();
+
+    assert_eq!(peak.level(), Ch32::new(0.0));
+    assert_eq!(peak.dbfs(), f32::NEG_INFINITY);
+
+    peak.process(&[Ch16::new(100), Ch16::new(-16_384), Ch16::new(8_192)]);
+    assert_eq!(peak.level(), Ch32::new(0.5));
+    assert!((peak.dbfs() + 6.020_6).abs() < 1e-4);
+
+    // Peak is held across blocks
+    peak.process(&[Ch16::new(1_000)]);
+    assert_eq!(peak.level(), Ch32::new(0.5));
+
+    peak.process(&[Ch16::MIN]);
+    assert_eq!(peak.level(), Ch32::new(1.0));
+    assert_eq!(peak.dbfs(), 0.0);
+
+    peak.reset();
+    assert_eq!(peak.level(), Ch32::new(0.0));
+
+    let mut peak = Peak::<Ch8>::new();
+
+    peak.process(&[Ch8::MAX]);
+    assert_eq!(peak.level(), Ch32::new(127.0 / 128.0));
+
+    let mut peak = Peak::<Ch24>::new();
+
+    peak.process(&[Ch24::new(-4_194_304)]);
+    assert_eq!(peak.level(), Ch32::new(0.5));
+}
+
+#[test]
+fn rms() {
+    let mut rms = Rms::<Ch32>::new(4);
+
+    // No complete window yet
+    rms.process(&[Ch32::new(1.0); 3]);
+    assert_eq!(rms.level(), Ch32::new(0.0));
+
+    rms.process(&[Ch32::new(-1.0)]);
+    assert_eq!(rms.level(), Ch32::new(1.0));
+
+    // Windows don't overlap
+    rms.process(&[Ch32::new(0.5), Ch32::new(-0.5), Ch32::new(0.5)]);
+    assert_eq!(rms.level(), Ch32::new(1.0));
+    rms.process(&[Ch32::new(-0.5), Ch32::new(0.0)]);
+    assert_eq!(rms.level(), Ch32::new(0.5));
+
+    rms.reset();
+    assert_eq!(rms.level(), Ch32::new(0.0));
+
+    // Full scale sine wave
+    let wave = sine::<4_800>(1_000.0 / 48_000.0, 0.0, 1.0).map(Ch64::new);
+    let mut rms = Rms::<Ch64>::new(4_800);
+
+    rms.process(&wave);
+    assert!((rms.dbfs() + 3.010_3).abs() < 1e-3);
+
+    let mut rms = Rms::<Ch16>::new(2);
+
+    rms.process(&[Ch16::new(16_384), Ch16::new(-16_384)]);
+    assert_eq!(rms.level(), Ch32::new(0.5));
+}
+
+#[test]
+#[should_panic(expected = "zero window")]
+fn rms_zero_window() {
+    let _ = Rms::<Ch32>::new(0);
+}
+
+#[test]
+fn true_peak() {
+    // Inter-sample peaks of sine waves are found to within 0.5 dB, once the
+    // filter has settled
+    for frequency in [0.02, 0.1, 0.25, 0.3, 0.4] {
+        for phase in [0.0, 0.3, 0.7, 1.1] {
+            let wave = sine::<2_048>(frequency, phase, 0.5).map(Ch64::new);
+            let mut true_peak = TruePeak::<Ch64>::new();
+
+            true_peak.process(&wave[..64]);
+            true_peak.reset();
+            true_peak.process(&wave[64..]);
+
+            let error = true_peak.dbfs() + 6.020_6;
+
+            assert!(error.abs() < 0.5, "{frequency} {phase}: {error}");
+        }
+    }
+
+    // Sample peak is 3 dB under the true peak at a quarter of the sample rate
+    let wave = sine::<256>(0.25, PI / 4.0, 1.0);
+    let mut peak = Peak::<Ch32>::new();
+    let mut true_peak = TruePeak::<Ch32>::new();
+    let wave = wave.map(|sample| Ch32::new(sample as f32));
+
+    peak.process(&wave[64..]);
+    true_peak.process(&wave[..64]);
+    true_peak.reset();
+    true_peak.process(&wave[64..]);
+    assert!((peak.dbfs() + 3.010_3).abs() < 1e-3);
+    assert!(true_peak.dbfs().abs() < 0.1);
+
+    // Integer channels
+    let wave = sine::<512>(0.1, 0.5, 16_384.0)
+        .map(|sample| Ch16::new(sample.round() as i16));
+    let mut true_peak = TruePeak::<Ch16>::new();
+
+    true_peak.process(&wave[..64]);
+    true_peak.reset();
+    true_peak.process(&wave[64..]);
+    assert!((true_peak.dbfs() + 6.020_6).abs() < 0.1);
+}
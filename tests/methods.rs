@@ -1,3 +1,5 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
 #[test]
 fn midpoint_unsigned() {
     assert_eq!(
@@ -467,3 +469,201 @@ fn clamp_signed_int_channels() {
         100,
     );
 }
+
+#[test]
+fn checked_arithmetic() {
+    use p_chan::{signed, unsigned};
+
+    let (a, b) = (unsigned::Ch8::new(200), unsigned::Ch8::new(100));
+
+    assert_eq!(a.checked_add(b), None);
+    assert_eq!(a.checked_sub(b), Some(unsigned::Ch8::new(100)));
+    assert_eq!(b.checked_sub(a), None);
+    assert_eq!(
+        unsigned::Ch8::new(15).checked_mul(unsigned::Ch8::new(17)),
+        Some(unsigned::Ch8::MAX),
+    );
+    assert_eq!(
+        unsigned::Ch10::new(1_000).checked_add(unsigned::Ch10::new(23)),
+        Some(unsigned::Ch10::MAX),
+    );
+    assert_eq!(
+        unsigned::Ch10::new(1_000).checked_add(unsigned::Ch10::new(24)),
+        None,
+    );
+    assert_eq!(
+        signed::Ch12::new(-2_000).checked_sub(signed::Ch12::new(48)),
+        Some(signed::Ch12::MIN),
+    );
+    assert_eq!(
+        signed::Ch12::new(-2_000).checked_sub(signed::Ch12::new(49)),
+        None,
+    );
+    assert_eq!(signed::Ch16::MIN.checked_mul(signed::Ch16::new(-1)), None);
+    assert_eq!(
+        signed::Ch24::new(-4_096).checked_mul(signed::Ch24::new(2_048)),
+        Some(signed::Ch24::MIN),
+    );
+    assert_eq!(signed::Ch24::MIN.checked_mul(signed::Ch24::new(-1)), None);
+    assert_eq!(
+        unsigned::Ch24::new(1 << 20).checked_mul(unsigned::Ch24::new(16)),
+        None,
+    );
+    assert_eq!(
+        signed::ChN::<4>::new(7).checked_add(signed::ChN::new(1)),
+        None,
+    );
+    assert_eq!(
+        unsigned::ChN::<32>::new(1).checked_sub(unsigned::ChN::new(1)),
+        Some(unsigned::ChN::MIN),
+    );
+
+    // Floats are checked against their range
+    assert_eq!(
+        signed::Ch32::new(0.75).checked_add(signed::Ch32::new(0.25)),
+        Some(signed::Ch32::MAX),
+    );
+    assert_eq!(
+        signed::Ch32::new(0.75).checked_add(signed::Ch32::new(0.5)),
+        None,
+    );
+    assert_eq!(
+        unsigned::Ch64::new(0.25).checked_sub(unsigned::Ch64::new(0.5)),
+        None,
+    );
+    assert_eq!(
+        signed::Ch64::new(-0.5).checked_mul(signed::Ch64::new(0.5)),
+        Some(signed::Ch64::new(-0.25)),
+    );
+    assert_eq!(
+        signed::Ch64::new(-0.5).checked_mul(signed::Ch64::new(f64::INFINITY)),
+        None,
+    );
+}
+
+#[test]
+fn wrapping_arithmetic() {
+    use p_chan::{signed, unsigned};
+
+    assert_eq!(
+        unsigned::Ch8::new(200).wrapping_add(unsigned::Ch8::new(100)),
+        unsigned::Ch8::new(44),
+    );
+    assert_eq!(
+        unsigned::Ch8::new(100).wrapping_sub(unsigned::Ch8::new(200)),
+        unsigned::Ch8::new(156),
+    );
+    assert_eq!(
+        unsigned::Ch10::new(1_023).wrapping_add(unsigned::Ch10::new(2)),
+        unsigned::Ch10::new(1),
+    );
+    assert_eq!(
+        unsigned::Ch12::new(0).wrapping_sub(unsigned::Ch12::new(1)),
+        unsigned::Ch12::MAX,
+    );
+    assert_eq!(
+        signed::Ch10::MAX.wrapping_add(signed::Ch10::new(1)),
+        signed::Ch10::MIN,
+    );
+    assert_eq!(
+        signed::Ch12::MIN.wrapping_sub(signed::Ch12::new(1)),
+        signed::Ch12::MAX,
+    );
+    assert_eq!(
+        signed::Ch16::MIN.wrapping_mul(signed::Ch16::new(-1)),
+        signed::Ch16::MIN,
+    );
+    assert_eq!(
+        signed::Ch24::new(4_194_304).wrapping_mul(signed::Ch24::new(3)),
+        signed::Ch24::new(-4_194_304),
+    );
+    assert_eq!(
+        unsigned::Ch24::new(16_777_215).wrapping_mul(unsigned::Ch24::MAX),
+        unsigned::Ch24::new(1),
+    );
+    assert_eq!(
+        signed::ChN::<4>::new(7).wrapping_add(signed::ChN::new(2)),
+        signed::ChN::new(-7),
+    );
+    assert_eq!(
+        unsigned::ChN::<32>::MAX.wrapping_mul(unsigned::ChN::MAX),
+        unsigned::ChN::new(1),
+    );
+    assert_eq!(
+        signed::ChN::<32>::MIN.wrapping_sub(signed::ChN::new(1)),
+        signed::ChN::MAX,
+    );
+}
+
+#[test]
+fn overflowing_arithmetic() {
+    use p_chan::{signed, unsigned};
+
+    assert_eq!(
+        unsigned::Ch8::new(200).overflowing_add(unsigned::Ch8::new(55)),
+        (unsigned::Ch8::MAX, false),
+    );
+    assert_eq!(
+        unsigned::Ch8::new(200).overflowing_add(unsigned::Ch8::new(56)),
+        (unsigned::Ch8::MIN, true),
+    );
+    assert_eq!(
+        unsigned::Ch16::new(1).overflowing_sub(unsigned::Ch16::new(2)),
+        (unsigned::Ch16::MAX, true),
+    );
+    assert_eq!(
+        signed::Ch8::new(-64).overflowing_mul(signed::Ch8::new(2)),
+        (signed::Ch8::MIN, false),
+    );
+    assert_eq!(
+        signed::Ch8::new(64).overflowing_mul(signed::Ch8::new(2)),
+        (signed::Ch8::MIN, true),
+    );
+    assert_eq!(
+        signed::Ch24::MAX.overflowing_add(signed::Ch24::MAX),
+        (signed::Ch24::new(-2), true),
+    );
+    assert_eq!(
+        unsigned::Ch24::new(1 << 20).overflowing_mul(unsigned::Ch24::new(15)),
+        (unsigned::Ch24::new(15 << 20), false),
+    );
+    assert_eq!(
+        unsigned::Ch24::new(1 << 20).overflowing_mul(unsigned::Ch24::new(17)),
+        (unsigned::Ch24::new(1 << 20), true),
+    );
+    assert_eq!(
+        unsigned::Ch24::new(0).overflowing_sub(unsigned::Ch24::new(1)),
+        (unsigned::Ch24::MAX, true),
+    );
+    assert_eq!(
+        signed::Ch24::MIN.overflowing_mul(signed::Ch24::new(-1)),
+        (signed::Ch24::MIN, true),
+    );
+    assert_eq!(
+        unsigned::ChN::<1>::MAX.overflowing_add(unsigned::ChN::MAX),
+        (unsigned::ChN::MIN, true),
+    );
+
+    // Floats clamp to their range instead of wrapping
+    assert_eq!(
+        signed::Ch32::new(0.75).overflowing_add(signed::Ch32::new(0.25)),
+        (signed::Ch32::MAX, false),
+    );
+    assert_eq!(
+        signed::Ch32::new(0.75).overflowing_add(signed::Ch32::new(0.5)),
+        (signed::Ch32::MAX, true),
+    );
+    assert_eq!(
+        unsigned::Ch64::new(0.25).overflowing_sub(unsigned::Ch64::new(0.5)),
+        (unsigned::Ch64::MIN, true),
+    );
+    assert_eq!(
+        signed::Ch64::new(-2.0).overflowing_mul(signed::Ch64::new(0.25)),
+        (signed::Ch64::new(-0.5), false),
+    );
+    assert_eq!(
+        signed::Ch64::new(-0.5)
+            .overflowing_mul(signed::Ch64::new(f64::INFINITY)),
+        (signed::Ch64::MIN, true),
+    );
+}
@@ -0,0 +1,148 @@
+#![cfg(all(feature = "mix", feature = "unsigned"))]
+
+use p_chan::{
+    frame::{ChannelLayout, Frame},
+    mix::Mix,
+    ops::Mixing,
+    signed, unsigned,
+};
+
+const H: f32 = core::f32::consts::FRAC_1_SQRT_2;
+
+fn gains<const I: usize, const O: usize>(mix: &Mix) -> [[f32; I]; O] {
+    assert_eq!((mix.inputs(), mix.outputs()), (I, O));
+
+    core::array::from_fn(|o| core::array::from_fn(|i| mix.gain(o, i)))
+}
+
+#[test]
+fn downmix_5_1() {
+    let mix = Mix::standard(ChannelLayout::SURROUND_5_1, ChannelLayout::STEREO)
+        .unwrap();
+
+    // L R C LFE Ls Rs
+    assert_eq!(
+        gains(&mix),
+        [[1.0, 0.0, H, 0.0, H, 0.0], [0.0, 1.0, H, 0.0, 0.0, H]],
+    );
+
+    let mix = Mix::standard(ChannelLayout::SURROUND_5_1, ChannelLayout::MONO)
+        .unwrap();
+    let [[l, r, c, lfe, ls, rs]] = gains(&mix);
+
+    assert_eq!([l, r, c, lfe], [H, H, 1.0, 0.0]);
+    assert!((ls - 0.5).abs() < 1e-7 && (rs - 0.5).abs() < 1e-7);
+}
+
+#[test]
+fn downmix_7_1() {
+    let mix =
+        Mix::standard(ChannelLayout::SURROUND_7_1, ChannelLayout::SURROUND_5_1)
+            .unwrap();
+
+    // L R C LFE Lb Rb Ls Rs
+    assert_eq!(
+        gains(&mix),
+        [
+            [1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 1.0],
+        ],
+    );
+}
+
+#[test]
+fn upmix() {
+    let mix =
+        Mix::standard(ChannelLayout::MONO, ChannelLayout::STEREO).unwrap();
+
+    assert_eq!(gains(&mix), [[H], [H]]);
+
+    let mix = Mix::standard(ChannelLayout::STEREO, ChannelLayout::SURROUND_5_1)
+        .unwrap();
+
+    assert_eq!(
+        gains(&mix),
+        [
+            [1.0, 0.0],
+            [0.0, 1.0],
+            [0.0; 2],
+            [0.0; 2],
+            [0.0; 2],
+            [0.0; 2]
+        ],
+    );
+    assert_eq!(
+        Mix::standard(ChannelLayout::Ambisonic(1), ChannelLayout::STEREO),
+        None,
+    );
+}
+
+#[test]
+fn normalized() {
+    let mix = Mix::new([[1.0, 1.0, 2.0], [0.5, 0.0, 0.0]]).normalized();
+
+    assert_eq!(gains(&mix), [[0.25, 0.25, 0.5], [0.125, 0.0, 0.0]]);
+    assert_eq!(Mix::identity(2).normalized(), Mix::identity(2));
+}
+
+#[test]
+fn interleaved_clipping() {
+    use signed::Ch16;
+
+    let mix = Mix::new([[1.0, 1.0], [1.0, -1.0]]);
+    let input = [30_000, 10_000, -30_000, -10_000].map(Ch16::new);
+    let mut output = [Ch16::new(0); 4];
+
+    Mixing::<Ch16>::interleaved(&mix, &input, &mut output);
+
+    assert_eq!(output, [32_767, 20_000, -32_768, -20_000].map(Ch16::new));
+}
+
+#[test]
+fn planar_float() {
+    use signed::Ch32;
+
+    let mix = Mix::new([[0.5, 0.5]]);
+    let (left, right) = ([Ch32::new(1.0), Ch32::new(0.5)], [Ch32::new(2.0); 2]);
+    let mut mono = [Ch32::new(0.0); 2];
+
+    Mixing::<Ch32>::planar(&mix, &[&left, &right], &mut [&mut mono]);
+
+    // Floats aren't clipped
+    assert_eq!(mono, [Ch32::new(1.5), Ch32::new(1.25)]);
+}
+
+#[test]
+fn unsigned_silence() {
+    use unsigned::Ch8;
+
+    let mix =
+        Mix::standard(ChannelLayout::STEREO, ChannelLayout::MONO).unwrap();
+    let frame: Frame<Ch8, 1> =
+        Mixing::<Ch8>::frame(&mix, Frame([Ch8::new(128); 2]));
+
+    assert_eq!(frame, Frame([Ch8::new(128)]));
+
+    let frame: Frame<Ch8, 1> =
+        Mixing::<Ch8>::frame(&mix, Frame([Ch8::MAX, Ch8::MAX]));
+
+    assert_eq!(frame, Frame([Ch8::MAX]));
+}
+
+#[test]
+#[should_panic(expected = "buffer length mismatch")]
+fn length_mismatch() {
+    use signed::Ch16;
+
+    let mix = Mix::identity(2);
+
+    Mixing::<Ch16>::interleaved(
+        &mix,
+        &[Ch16::new(0); 4],
+        &mut [Ch16::new(0); 2],
+    );
+}
@@ -0,0 +1,133 @@
+#![cfg(feature = "model")]
+
+use p_chan::{
+    ops::Conversion,
+    pixel::{Hsl, Hsv, Hwb, Lab, Lch, Oklab, Oklch, Rgb},
+    unsigned::{Ch32, Ch64},
+};
+
+fn rgb(r: f64, g: f64, b: f64) -> Rgb<Ch64> {
+    Rgb::new(Ch64::new(r), Ch64::new(g), Ch64::new(b))
+}
+
+fn assert_close<T: Into<[Ch64; 3]>>(actual: T, expected: [f64; 3], eps: f64) {
+    let actual = actual.into().map(Ch64::into_inner);
+
+    for (a, e) in actual.into_iter().zip(expected) {
+        assert!((a - e).abs() <= eps, "{actual:?} != {expected:?}");
+    }
+}
+
+const COLORS: [[f64; 3]; 9] = [
+    [0.0, 0.0, 0.0],
+    [1.0, 1.0, 1.0],
+    [0.5, 0.5, 0.5],
+    [1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.2, 0.4, 0.6],
+    [0.9, 0.1, 0.5],
+    [0.3, 0.8, 0.05],
+];
+
+#[test]
+fn hsv() {
+    let convert = Conversion::<Rgb<Ch64>, Hsv<Ch64>>::convert;
+
+    assert_close(convert(rgb(1.0, 0.0, 0.0)), [0.0, 1.0, 1.0], 0.0);
+    assert_close(convert(rgb(0.0, 0.5, 0.0)), [1.0 / 3.0, 1.0, 0.5], 1e-15);
+    assert_close(convert(rgb(1.0, 0.0, 1.0)), [5.0 / 6.0, 1.0, 1.0], 1e-15);
+    assert_close(convert(rgb(0.5, 0.5, 0.5)), [0.0, 0.0, 0.5], 0.0);
+}
+
+#[test]
+fn hsl() {
+    let convert = Conversion::<Rgb<Ch64>, Hsl<Ch64>>::convert;
+
+    assert_close(convert(rgb(1.0, 0.0, 0.0)), [0.0, 1.0, 0.5], 0.0);
+    assert_close(convert(rgb(0.5, 1.0, 1.0)), [0.5, 1.0, 0.75], 1e-15);
+    assert_close(convert(rgb(1.0, 1.0, 1.0)), [0.0, 0.0, 1.0], 0.0);
+}
+
+#[test]
+fn hwb() {
+    let convert = Conversion::<Rgb<Ch64>, Hwb<Ch64>>::convert;
+    let back = Conversion::<Hwb<Ch64>, Rgb<Ch64>>::convert;
+
+    assert_close(convert(rgb(0.75, 0.25, 0.25)), [0.0, 0.25, 0.25], 0.0);
+    // Whiteness and blackness over 1 are normalized to gray
+    let gray = Hwb::new(Ch64::new(0.3), Ch64::new(0.6), Ch64::new(0.9));
+
+    assert_close(back(gray), [0.4; 3], 1e-15);
+}
+
+#[test]
+fn lab() {
+    let convert = Conversion::<Rgb<Ch64>, Lab<Ch64>>::convert;
+
+    assert_close(convert(rgb(1.0, 1.0, 1.0)), [1.0, 0.0, 0.0], 1e-12);
+    assert_close(convert(rgb(0.0, 0.0, 0.0)), [0.0, 0.0, 0.0], 1e-12);
+    // Reference values for sRGB red, green, and blue (L*, a*, b* / 100)
+    assert_close(convert(rgb(1.0, 0.0, 0.0)), [0.5324, 0.8009, 0.6720], 1e-4);
+    assert_close(convert(rgb(0.0, 1.0, 0.0)), [0.8774, -0.8618, 0.8318], 1e-4);
+    assert_close(convert(rgb(0.0, 0.0, 1.0)), [0.3230, 0.7919, -1.0786], 1e-4);
+}
+
+#[test]
+fn oklab() {
+    let convert = Conversion::<Rgb<Ch64>, Oklab<Ch64>>::convert;
+    let polar = Conversion::<Rgb<Ch64>, Oklch<Ch64>>::convert;
+
+    assert_close(convert(rgb(1.0, 1.0, 1.0)), [1.0, 0.0, 0.0], 1e-7);
+    // Reference values for sRGB red, green, and blue
+    assert_close(convert(rgb(1.0, 0.0, 0.0)), [0.6279, 0.2249, 0.1258], 1e-4);
+    assert_close(convert(rgb(0.0, 1.0, 0.0)), [0.8664, -0.2339, 0.1795], 1e-4);
+    assert_close(
+        convert(rgb(0.0, 0.0, 1.0)),
+        [0.4520, -0.0325, -0.3115],
+        1e-4,
+    );
+    // Hue of 29.2338° for red
+    let red = polar(rgb(1.0, 0.0, 0.0));
+
+    assert_close(red, [0.6279, 0.2577, 29.2338 / 360.0], 1e-4);
+}
+
+#[test]
+fn round_trip() {
+    for [r, g, b] in COLORS {
+        let pixel = rgb(r, g, b);
+
+        macro_rules! round_trip {
+            ($($model: ident),*) => {$(
+                let model = Conversion::<Rgb<Ch64>, $model<Ch64>>::convert(pixel);
+                let back = Conversion::<$model<Ch64>, Rgb<Ch64>>::convert(model);
+
+                assert_close(back, [r, g, b], 1e-9);
+            )*};
+        }
+
+        round_trip!(Hsv, Hsl, Hwb, Lab, Lch, Oklab, Oklch);
+
+        let lab = Conversion::<Rgb<Ch64>, Lab<Ch64>>::convert(pixel);
+        let lch = Conversion::<Lab<Ch64>, Lch<Ch64>>::convert(lab);
+        let lab = Conversion::<Lch<Ch64>, Lab<Ch64>>::convert(lch);
+
+        assert_close(
+            Conversion::<Lab<Ch64>, Rgb<Ch64>>::convert(lab),
+            [r, g, b],
+            1e-9,
+        );
+    }
+}
+
+#[test]
+fn single_precision() {
+    let pixel = Rgb::new(Ch32::new(0.2), Ch32::new(0.4), Ch32::new(0.6));
+    let oklch = Conversion::<Rgb<Ch32>, Oklch<Ch32>>::convert(pixel);
+    let back = Conversion::<Oklch<Ch32>, Rgb<Ch32>>::convert(oklch);
+
+    for (a, e) in <[Ch32; 3]>::from(back).into_iter().zip([0.2, 0.4, 0.6]) {
+        assert!((a.into_inner() - e).abs() < 1e-6);
+    }
+}
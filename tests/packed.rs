@@ -0,0 +1,127 @@
+#![cfg(feature = "packed")]
+
+use p_chan::{
+    ops::Conversion,
+    packed::{Ch10, Ch11, Rg11b10, Rgb9e5},
+    unsigned::Ch32,
+};
+
+fn ch11(float: f32) -> u16 {
+    Conversion::<Ch32, Ch11>::convert(Ch32::new(float)).to_bits()
+}
+
+fn ch10(float: f32) -> u16 {
+    Conversion::<Ch32, Ch10>::convert(Ch32::new(float)).to_bits()
+}
+
+#[test]
+fn encode_minifloat() {
+    assert_eq!(ch11(0.0), 0);
+    assert_eq!(ch11(1.0), 15 << 6);
+    assert_eq!(ch11(0.5), 14 << 6);
+    assert_eq!(ch11(1.5), (15 << 6) | 32);
+    assert_eq!(ch10(1.0), 15 << 5);
+    assert_eq!(ch10(1.5), (15 << 5) | 16);
+    // Round to nearest, ties to even
+    assert_eq!(ch11(1.0 + 1.0 / 128.0), 15 << 6);
+    assert_eq!(ch11(1.0 + 3.0 / 128.0), (15 << 6) | 2);
+    assert_eq!(ch11(1.0 + 1.1 / 128.0), (15 << 6) | 1);
+    // Subnormal
+    assert_eq!(ch11(2.0f32.powi(-15)), 32);
+    assert_eq!(ch11(2.0f32.powi(-20)), 1);
+    assert_eq!(ch11(2.0f32.powi(-22)), 0);
+    // Out of range
+    assert_eq!(ch11(65_024.0), Ch11::MAX.to_bits());
+    assert_eq!(ch11(65_100.0), Ch11::MAX.to_bits());
+    assert_eq!(ch11(65_536.0), Ch11::INFINITY.to_bits());
+    assert_eq!(ch10(64_512.0), Ch10::MAX.to_bits());
+    assert_eq!(ch10(65_000.0), Ch10::MAX.to_bits());
+    assert_eq!(ch10(65_100.0), Ch10::INFINITY.to_bits());
+    assert_eq!(ch11(f32::INFINITY), Ch11::INFINITY.to_bits());
+    assert_eq!(ch11(f32::NEG_INFINITY), 0);
+    assert_eq!(ch11(-1.0), 0);
+    assert_eq!(ch11(f32::NAN), 0);
+}
+
+#[test]
+fn decode_minifloat() {
+    let decode = |bits| {
+        Conversion::<Ch11, Ch32>::convert(Ch11::from_bits(bits)).into_inner()
+    };
+
+    assert_eq!(decode(0), 0.0);
+    assert_eq!(decode(15 << 6), 1.0);
+    assert_eq!(decode(1), 2.0f32.powi(-20));
+    assert_eq!(decode(0x7bf), 65_024.0);
+    assert_eq!(decode(0x7c0), f32::INFINITY);
+    // NaN
+    assert_eq!(decode(0x7c1), 0.0);
+    assert_eq!(Ch11::from_bits(0x7ff), Ch11::MIN);
+
+    for bits in 0..0x7c1 {
+        let chan = Ch11::from_bits(bits);
+        let float = Conversion::<Ch11, Ch32>::convert(chan);
+
+        assert_eq!(Conversion::<Ch32, Ch11>::convert(float), chan);
+    }
+
+    for bits in 0..0x3e1 {
+        let chan = Ch10::from_bits(bits);
+        let float = Conversion::<Ch10, Ch32>::convert(chan);
+
+        assert_eq!(Conversion::<Ch32, Ch10>::convert(float), chan);
+    }
+}
+
+#[test]
+fn rg11b10() {
+    let pixel = Conversion::<[Ch32; 3], Rg11b10>::convert([
+        Ch32::new(1.0),
+        Ch32::new(0.5),
+        Ch32::new(f32::INFINITY),
+    ]);
+
+    assert_eq!(
+        pixel.to_bits(),
+        (15 << 6) | ((14 << 6) << 11) | ((31 << 5) << 22),
+    );
+    assert_eq!(
+        Conversion::<Rg11b10, [Ch32; 3]>::convert(pixel),
+        [Ch32::new(1.0), Ch32::new(0.5), Ch32::new(f32::INFINITY)],
+    );
+    // NaN green
+    assert_eq!(Rg11b10::from_bits(0x7c1 << 11).to_bits(), 0);
+}
+
+#[test]
+fn rgb9e5() {
+    let pixel = Conversion::<[Ch32; 3], Rgb9e5>::convert([
+        Ch32::new(1.0),
+        Ch32::new(0.5),
+        Ch32::new(0.25),
+    ]);
+
+    assert_eq!(pixel.to_bits(), 256 | (128 << 9) | (64 << 18) | (16 << 27));
+    assert_eq!(
+        Conversion::<Rgb9e5, [Ch32; 3]>::convert(pixel),
+        [Ch32::new(1.0), Ch32::new(0.5), Ch32::new(0.25)],
+    );
+
+    let convert = |rgb: [f32; 3]| {
+        Conversion::<Rgb9e5, [Ch32; 3]>::convert(
+            Conversion::<[Ch32; 3], Rgb9e5>::convert(rgb.map(Ch32::new)),
+        )
+        .map(Ch32::into_inner)
+    };
+
+    assert_eq!(convert([0.0; 3]), [0.0; 3]);
+    assert_eq!(
+        convert([f32::INFINITY, -1.0, f32::NAN]),
+        [Rgb9e5::MAX_VALUE, 0.0, 0.0],
+    );
+    assert_eq!(convert([1e6, 0.0, 0.0]), [65_408.0, 0.0, 0.0]);
+    // Mantissa rounding overflow bumps the shared exponent
+    assert_eq!(convert([1.999, 0.0, 0.0]), [2.0, 0.0, 0.0]);
+    // Smaller channels lose precision to the shared exponent
+    assert_eq!(convert([100.0, 0.3, 0.0]), [100.0, 0.25, 0.0]);
+}
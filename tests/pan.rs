@@ -0,0 +1,123 @@
+#![cfg(all(feature = "pan", feature = "unsigned"))]
+
+use p_chan::{
+    frame::Frame,
+    gain::Gain,
+    ops::Panning,
+    pan::{Pan, PanLaw},
+    signed, unsigned,
+};
+
+const LAWS: [PanLaw; 3] =
+    [PanLaw::Linear, PanLaw::ConstantPower, PanLaw::Compromise];
+
+#[test]
+fn laws() {
+    for (law, db) in LAWS.into_iter().zip([-6.020_6, -3.010_3, -4.515_45]) {
+        let center = Pan::new(0.0, law);
+
+        assert_eq!(center.left(), center.right());
+        assert!((center.left().to_db() - db).abs() < 1e-4, "{law:?}");
+
+        // Hard left and right are exact
+        assert_eq!(
+            Pan::new(-1.0, law),
+            Pan::from_gains(Gain::UNITY, Gain::SILENCE),
+        );
+        assert_eq!(
+            Pan::new(1.0, law),
+            Pan::from_gains(Gain::SILENCE, Gain::UNITY),
+        );
+        // Clamped
+        assert_eq!(Pan::new(-3.0, law), Pan::new(-1.0, law));
+
+        // Symmetric, and monotonic
+        let mut last = Pan::new(-1.0, law);
+
+        for i in -100..=100 {
+            let position = i as f32 / 100.0;
+            let pan = Pan::new(position, law);
+            let mirror = Pan::new(-position, law);
+
+            assert_eq!(pan.left(), mirror.right());
+            assert!(pan.left() <= last.left() && pan.right() >= last.right());
+            last = pan;
+        }
+    }
+
+    // Constant power
+    for i in -10..=10 {
+        let pan = Pan::new(i as f32 / 10.0, PanLaw::ConstantPower);
+        let (left, right) = (pan.left().into_inner(), pan.right().into_inner());
+
+        assert!((left * left + right * right - 1.0).abs() < 1e-6);
+    }
+
+    // Constant amplitude
+    let pan = Pan::new(0.5, PanLaw::Linear);
+
+    assert_eq!(pan, Pan::from_gains(Gain::new(0.25), Gain::new(0.75)));
+}
+
+#[test]
+fn balance() {
+    for law in LAWS {
+        assert_eq!(Pan::balance(0.0, law), Pan::CENTER);
+        assert_eq!(
+            Pan::balance(1.0, law),
+            Pan::from_gains(Gain::SILENCE, Gain::UNITY),
+        );
+        assert_eq!(
+            Pan::balance(-1.0, law),
+            Pan::from_gains(Gain::UNITY, Gain::SILENCE),
+        );
+
+        let balance = Pan::balance(-0.5, law);
+
+        assert_eq!(balance.left(), Gain::UNITY);
+        assert!(balance.right() < Gain::UNITY);
+    }
+
+    assert_eq!(
+        Pan::balance(0.5, PanLaw::Linear),
+        Pan::from_gains(Gain::new(0.5), Gain::UNITY),
+    );
+}
+
+#[test]
+fn panning() {
+    use signed::{Ch8, Ch32};
+
+    const PAN: Pan = Pan::new(0.5, PanLaw::Linear);
+    const FRAME: Frame<Ch32, 2> = Panning(Ch32::new(0.5)).pan(PAN);
+
+    assert_eq!(FRAME, Frame([Ch32::new(0.125), Ch32::new(0.375)]));
+    assert_eq!(
+        Panning(Ch8::new(-100)).pan(PAN),
+        Frame([Ch8::new(-25), Ch8::new(-75)]),
+    );
+
+    // Unsigned channels pan around the middle (silence)
+    assert_eq!(
+        Panning(unsigned::Ch8::new(228)).pan(PAN),
+        Frame([unsigned::Ch8::new(153), unsigned::Ch8::new(203)]),
+    );
+    assert_eq!(
+        Panning(unsigned::Ch16::new(32_768))
+            .pan(Pan::new(-0.3, PanLaw::Compromise)),
+        Frame([unsigned::Ch16::new(32_768); 2]),
+    );
+
+    let frame = Frame([Ch32::new(0.5), Ch32::new(-0.5)]);
+
+    assert_eq!(Panning(frame).pan(Pan::CENTER), frame);
+    assert_eq!(
+        Panning(frame).pan(Pan::balance(0.5, PanLaw::Linear)),
+        Frame([Ch32::new(0.25), Ch32::new(-0.5)]),
+    );
+    assert_eq!(
+        Panning(Frame([signed::Ch16::MAX; 2]))
+            .pan(Pan::from_gains(Gain::new(2.0), Gain::UNITY)),
+        Frame([signed::Ch16::MAX; 2]),
+    );
+}
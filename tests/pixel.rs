@@ -0,0 +1,86 @@
+#![cfg(all(feature = "pixel", feature = "signed"))]
+
+use core::mem;
+
+use p_chan::{
+    ops::Conversion,
+    pixel::{Bgra, Gray, GrayAlpha, Rgb, Rgba},
+    signed,
+    unsigned::{Ch8, Ch16, Ch32},
+};
+
+#[test]
+fn layout() {
+    assert_eq!(mem::size_of::<Rgba<Ch8>>(), 4);
+    assert_eq!(mem::size_of::<Rgb<Ch16>>(), 6);
+    assert_eq!(mem::size_of::<GrayAlpha<Ch32>>(), 8);
+
+    let pixel = Bgra::new(Ch8::new(1), Ch8::new(2), Ch8::new(3), Ch8::new(4));
+
+    assert_eq!(<[Ch8; 4]>::from(pixel).map(Ch8::into_inner), [1, 2, 3, 4]);
+    assert_eq!(Bgra::from(<[Ch8; 4]>::from(pixel)), pixel);
+}
+
+#[test]
+fn constants() {
+    assert_eq!(Rgb::<Ch8>::BLACK, Rgb::new(Ch8::MIN, Ch8::MIN, Ch8::MIN));
+    assert_eq!(Rgb::<Ch8>::WHITE, Rgb::new(Ch8::MAX, Ch8::MAX, Ch8::MAX));
+    assert_eq!(
+        Rgba::<Ch32>::BLACK,
+        Rgba::new(Ch32::MIN, Ch32::MIN, Ch32::MIN, Ch32::MAX),
+    );
+    assert_eq!(Rgba::<Ch32>::TRANSPARENT, Rgba::default());
+    assert_eq!(
+        Bgra::<signed::Ch16>::TRANSPARENT,
+        Bgra::from([signed::Ch16::MIN; 4]),
+    );
+    assert_eq!(GrayAlpha::<Ch8>::WHITE, GrayAlpha::from([Ch8::MAX; 2]));
+}
+
+#[test]
+fn convert_layout() {
+    let gray = Gray::new(Ch8::new(100));
+    let rgb = Rgb::new(Ch8::new(1), Ch8::new(2), Ch8::new(3));
+    let rgba = Conversion::<Rgb<Ch8>, Rgba<Ch8>>::convert(rgb);
+    let bgra = Conversion::<Rgb<Ch8>, Bgra<Ch8>>::convert(rgb);
+
+    assert_eq!(rgba, Rgba::new(rgb.r, rgb.g, rgb.b, Ch8::MAX));
+    assert_eq!(bgra, Bgra::new(rgb.b, rgb.g, rgb.r, Ch8::MAX));
+    assert_eq!(Conversion::<Rgba<Ch8>, Bgra<Ch8>>::convert(rgba), bgra);
+    assert_eq!(Conversion::<Bgra<Ch8>, Rgba<Ch8>>::convert(bgra), rgba);
+    assert_eq!(Conversion::<Bgra<Ch8>, Rgb<Ch8>>::convert(bgra), rgb);
+    assert_eq!(
+        Conversion::<Gray<Ch8>, Rgb<Ch8>>::convert(gray),
+        Rgb::from([gray.v; 3]),
+    );
+    assert_eq!(
+        Conversion::<Gray<Ch8>, Rgba<Ch8>>::convert(gray),
+        Rgba::new(gray.v, gray.v, gray.v, Ch8::MAX),
+    );
+}
+
+#[test]
+fn convert_format() {
+    let pixel = Rgba::new(Ch8::MAX, Ch8::new(51), Ch8::MIN, Ch8::MAX);
+    let float = pixel.map(Conversion::<Ch8, Ch32>::convert);
+
+    assert_eq!(
+        float,
+        Rgba::new(Ch32::MAX, Ch32::new(0.2), Ch32::MIN, Ch32::MAX),
+    );
+    assert_eq!(float.map(Conversion::<Ch32, Ch8>::convert), pixel);
+}
+
+#[test]
+fn arithmetic() {
+    let a = Rgb::new(Ch8::new(200), Ch8::new(10), Ch8::new(100));
+    let b = Rgb::new(Ch8::new(100), Ch8::new(20), Ch8::new(100));
+
+    assert_eq!(a + b, Rgb::new(Ch8::MAX, Ch8::new(30), Ch8::new(200)));
+    assert_eq!(a - b, Rgb::new(Ch8::new(100), Ch8::MIN, Ch8::MIN));
+    assert_eq!(a.midpoint(b), Rgb::new(Ch8::new(150), Ch8::new(15), b.b));
+    assert_eq!(
+        a.clamp(Rgb::from([Ch8::new(50); 3]), Rgb::from([Ch8::new(150); 3])),
+        Rgb::new(Ch8::new(150), Ch8::new(50), Ch8::new(100)),
+    );
+}
@@ -0,0 +1,250 @@
+#![cfg(feature = "planar")]
+
+use p_chan::{
+    ops::Conversion,
+    pixel::{Rgb, Rgba},
+    planar::{
+        Chroma, Filter, P010, Plane, PlaneMut, Siting, Subsampling, Yuv, YuvMut,
+    },
+    unsigned::{Ch8, Ch10, Ch64},
+    ycbcr::{Matrix, Range},
+};
+
+const RED: Rgb<Ch8> = Rgb::new(Ch8::MAX, Ch8::MIN, Ch8::MIN);
+
+#[test]
+fn chroma_size() {
+    assert_eq!(Subsampling::Yuv444.chroma_size(5, 3), (5, 3));
+    assert_eq!(Subsampling::Yuv422.chroma_size(5, 3), (3, 3));
+    assert_eq!(Subsampling::Yuv420.chroma_size(5, 3), (3, 2));
+}
+
+#[test]
+fn i420_round_trip() {
+    // Left half red, right half white (aligned to chroma samples)
+    let mut rgb = [Rgb::<Ch8>::WHITE; 4 * 2];
+
+    rgb[0] = RED;
+    rgb[1] = RED;
+    rgb[4] = RED;
+    rgb[5] = RED;
+
+    let (mut y, mut cb, mut cr) = ([Ch8::MIN; 8], [Ch8::MIN; 2], [Ch8::MIN; 2]);
+    let mut yuv = YuvMut::new(
+        PlaneMut::new(&mut y, 4, 2, 4),
+        Chroma::Planar {
+            cb: PlaneMut::new(&mut cb, 2, 1, 2),
+            cr: PlaneMut::new(&mut cr, 2, 1, 2),
+        },
+        Subsampling::Yuv420,
+        Siting::Center,
+    );
+
+    yuv.pack_rgb(
+        &Plane::new(&rgb, 4, 2, 4),
+        Matrix::Bt709,
+        Range::Limited,
+        Filter::Nearest,
+    );
+
+    let mut unpacked = [Rgb::<Ch8>::BLACK; 8];
+
+    yuv.as_yuv().unpack_rgb(
+        &mut PlaneMut::new(&mut unpacked, 4, 2, 4),
+        Matrix::Bt709,
+        Range::Limited,
+        Filter::Nearest,
+    );
+
+    assert_eq!(y.map(Ch8::into_inner), [63, 63, 235, 235, 63, 63, 235, 235]);
+    assert_eq!(cb.map(Ch8::into_inner), [102, 128]);
+    assert_eq!(cr.map(Ch8::into_inner), [240, 128]);
+    // Quantization loses precision
+    assert_eq!(unpacked[0], Rgb::new(Ch8::MAX, Ch8::new(1), Ch8::MIN));
+    assert_eq!(unpacked[3], Rgb::<Ch8>::WHITE);
+}
+
+#[test]
+fn nv12_stride() {
+    let rgb = [RED; 4];
+    // Luma and chroma rows are padded to a stride of 4
+    let (mut y, mut cbcr) = ([Ch8::MIN; 6], [Ch8::MIN; 2]);
+    let mut yuv = YuvMut::new(
+        PlaneMut::new(&mut y, 2, 2, 4),
+        Chroma::SemiPlanar(PlaneMut::new(&mut cbcr, 2, 1, 4)),
+        Subsampling::Yuv420,
+        Siting::Left,
+    );
+
+    yuv.pack_rgba(
+        &Plane::new(&rgb.map(|p| Rgba::new(p.r, p.g, p.b, Ch8::MIN)), 2, 2, 2),
+        Matrix::Bt709,
+        Range::Limited,
+        Filter::Linear,
+    );
+
+    let mut rgba = [Rgba::<Ch8>::TRANSPARENT; 4];
+
+    yuv.as_yuv().unpack_rgba(
+        &mut PlaneMut::new(&mut rgba, 2, 2, 2),
+        Matrix::Bt709,
+        Range::Limited,
+        Filter::Linear,
+    );
+
+    assert_eq!(y.map(Ch8::into_inner), [63, 63, 0, 0, 63, 63]);
+    assert_eq!(cbcr.map(Ch8::into_inner), [102, 240]);
+    assert!(rgba.iter().all(|p| p.a == Ch8::MAX && p.r == Ch8::MAX));
+}
+
+/// Upsample a 4:2:2 row of chroma `[0, 1]` (full range, so code values map
+/// directly to blue-difference).
+fn upsample_422(siting: Siting, filter: Filter) -> [f64; 4] {
+    let y = [Ch64::new(0.5); 4];
+    let cb = [Ch64::new(0.0), Ch64::new(1.0)];
+    let cr = [Ch64::new(0.5); 2];
+    let yuv = Yuv::new(
+        Plane::new(&y, 4, 1, 4),
+        Chroma::Planar {
+            cb: Plane::new(&cb, 2, 1, 2),
+            cr: Plane::new(&cr, 2, 1, 2),
+        },
+        Subsampling::Yuv422,
+        siting,
+    );
+    let mut rgb = [Rgb::<Ch64>::BLACK; 4];
+
+    yuv.unpack_rgb(
+        &mut PlaneMut::new(&mut rgb, 4, 1, 4),
+        Matrix::Bt709,
+        Range::Full,
+        filter,
+    );
+
+    // Recover blue-difference from blue
+    rgb.map(|p| (p.b.into_inner() - 0.5) / (2.0 * (1.0 - 0.0722)) + 0.5)
+}
+
+fn assert_close(actual: [f64; 4], expected: [f64; 4]) {
+    for (a, e) in actual.into_iter().zip(expected) {
+        assert!((a - e).abs() < 1e-12, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn upsample_siting() {
+    assert_close(
+        upsample_422(Siting::Left, Filter::Linear),
+        [0.0, 0.5, 1.0, 1.0],
+    );
+    assert_close(
+        upsample_422(Siting::Center, Filter::Linear),
+        [0.0, 0.25, 0.75, 1.0],
+    );
+    assert_close(
+        upsample_422(Siting::Left, Filter::Nearest),
+        [0.0, 0.0, 1.0, 1.0],
+    );
+    assert_close(
+        upsample_422(Siting::Center, Filter::Nearest),
+        [0.0, 0.0, 1.0, 1.0],
+    );
+}
+
+#[test]
+fn downsample_siting() {
+    // Gray, except for one blue pixel
+    let mut rgb = [Rgb::new(Ch64::new(0.5), Ch64::new(0.5), Ch64::new(0.5)); 4];
+
+    rgb[2].b = Ch64::new(1.0);
+
+    let downsample = |siting, filter| {
+        let (mut y, mut cb, mut cr) =
+            ([Ch64::MIN; 4], [Ch64::MIN; 2], [Ch64::MIN; 2]);
+        let mut yuv = YuvMut::new(
+            PlaneMut::new(&mut y, 4, 1, 4),
+            Chroma::Planar {
+                cb: PlaneMut::new(&mut cb, 2, 1, 2),
+                cr: PlaneMut::new(&mut cr, 2, 1, 2),
+            },
+            Subsampling::Yuv422,
+            siting,
+        );
+
+        yuv.pack_rgb(
+            &Plane::new(&rgb, 4, 1, 4),
+            Matrix::Bt709,
+            Range::Full,
+            filter,
+        );
+        cb.map(|c| c.into_inner() - 0.5)
+    };
+    let full = (1.0 - 0.0722) * 0.5 / (2.0 * (1.0 - 0.0722));
+    let [left, right] = downsample(Siting::Left, Filter::Linear);
+
+    // Triangle filter weights (normalized where clipped at the edge)
+    assert_eq!(left, 0.0);
+    assert!((right - full * 1.0 / 2.0).abs() < 1e-12);
+
+    let [left, right] = downsample(Siting::Center, Filter::Linear);
+
+    assert!((left - full * 0.25 / 1.75).abs() < 1e-12);
+    assert!((right - full * 0.75 / 1.75).abs() < 1e-12);
+    assert_eq!(downsample(Siting::Left, Filter::Nearest), [0.0, full]);
+}
+
+#[test]
+fn p010() {
+    // 10-bit code values in the most significant bits
+    let rgb = [Rgb::new(Ch10::MAX, Ch10::MIN, Ch10::MIN); 4];
+    let (mut y, mut cbcr) = ([P010::default(); 4], [P010::default(); 2]);
+    let mut yuv = YuvMut::new(
+        PlaneMut::new(&mut y, 2, 2, 2),
+        Chroma::SemiPlanar(PlaneMut::new(&mut cbcr, 2, 1, 2)),
+        Subsampling::Yuv420,
+        Siting::TopLeft,
+    );
+
+    yuv.pack_rgb(
+        &Plane::new(&rgb, 2, 2, 2),
+        Matrix::Bt2020,
+        Range::Limited,
+        Filter::Linear,
+    );
+
+    let mut unpacked = [Rgb::<Ch10>::BLACK; 4];
+
+    yuv.as_yuv().unpack_rgb(
+        &mut PlaneMut::new(&mut unpacked, 2, 2, 2),
+        Matrix::Bt2020,
+        Range::Limited,
+        Filter::Linear,
+    );
+
+    assert_eq!(y.map(P010::to_bits), [294 << 6; 4]);
+    assert_eq!(cbcr.map(P010::to_bits), [387 << 6, 960 << 6]);
+    assert_eq!(unpacked, rgb);
+
+    // The low 6 bits are ignored
+    let sample = P010::from_bits(0xffff);
+
+    assert_eq!(sample.to_bits(), 0xffc0);
+    assert_eq!(Conversion::<P010, Ch10>::convert(sample), Ch10::MAX);
+    assert_eq!(
+        Conversion::<Ch10, P010>::convert(Ch10::new(512)),
+        P010::from_bits(0x8000)
+    );
+}
+
+#[test]
+#[should_panic(expected = "chroma size mismatch")]
+fn chroma_mismatch() {
+    let (y, c) = ([Ch8::MIN; 4], [Ch8::MIN; 4]);
+
+    Yuv::new(
+        Plane::new(&y, 2, 2, 2),
+        Chroma::SemiPlanar(Plane::new(&c, 1, 1, 1)),
+        Subsampling::Yuv420,
+        Siting::Left,
+    );
+}
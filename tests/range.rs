@@ -0,0 +1,133 @@
+#![cfg(feature = "range")]
+
+use p_chan::{
+    ops::Remapping,
+    range::{Component, Range, Remap},
+    unsigned::{Ch8, Ch10, Ch16},
+};
+
+const TO_FULL: Remap = Remap {
+    from: Range::Limited,
+    to: Range::Full,
+    component: Component::Luma,
+    clip: false,
+};
+
+const TO_LIMITED: Remap = Remap {
+    from: Range::Full,
+    to: Range::Limited,
+    component: Component::Luma,
+    clip: false,
+};
+
+fn remap_8(code: u8, remap: Remap) -> u8 {
+    Remapping::<Ch8, Ch8>::remap(Ch8::new(code), remap).into_inner()
+}
+
+#[test]
+fn luma_8() {
+    assert_eq!(remap_8(16, TO_FULL), 0);
+    assert_eq!(remap_8(235, TO_FULL), 255);
+    assert_eq!(remap_8(126, TO_FULL), 128);
+    assert_eq!(remap_8(0, TO_FULL), 0);
+    assert_eq!(remap_8(255, TO_FULL), 255);
+    assert_eq!(remap_8(0, TO_LIMITED), 16);
+    assert_eq!(remap_8(255, TO_LIMITED), 235);
+    assert_eq!(remap_8(128, TO_LIMITED), 126);
+
+    // Exhaustively check correct rounding
+    for code in 0..=255 {
+        let exact = (f64::from(code) - 16.0) * 255.0 / 219.0;
+        let expected = exact.round().clamp(0.0, 255.0) as u8;
+
+        assert_eq!(remap_8(code, TO_FULL), expected);
+
+        let exact = f64::from(code) * 219.0 / 255.0 + 16.0;
+
+        assert_eq!(remap_8(code, TO_LIMITED), exact.round() as u8);
+    }
+}
+
+#[test]
+fn chroma_8() {
+    let to_full = Remap {
+        component: Component::Chroma,
+        ..TO_FULL
+    };
+    let to_limited = Remap {
+        component: Component::Chroma,
+        ..TO_LIMITED
+    };
+
+    assert_eq!(remap_8(128, to_full), 128);
+    // Full range chroma is centered on 128, so the minimum is 0.5
+    assert_eq!(remap_8(16, to_full), 1);
+    assert_eq!(remap_8(240, to_full), 255);
+    assert_eq!(remap_8(128, to_limited), 128);
+    assert_eq!(remap_8(0, to_limited), 16);
+    assert_eq!(remap_8(255, to_limited), 240);
+}
+
+#[test]
+fn clip() {
+    let limited = Remap {
+        from: Range::Limited,
+        to: Range::Limited,
+        component: Component::Luma,
+        clip: false,
+    };
+    let clipped = Remap {
+        clip: true,
+        ..limited
+    };
+
+    assert_eq!(remap_8(4, limited), 4);
+    assert_eq!(remap_8(250, limited), 250);
+    assert_eq!(remap_8(4, clipped), 16);
+    assert_eq!(remap_8(250, clipped), 235);
+
+    let chroma = Remap {
+        component: Component::Chroma,
+        ..clipped
+    };
+
+    assert_eq!(remap_8(250, chroma), 240);
+    assert_eq!(remap_8(4, chroma), 16);
+}
+
+#[test]
+fn bit_depth() {
+    let limited = Remap {
+        from: Range::Limited,
+        to: Range::Limited,
+        component: Component::Luma,
+        clip: false,
+    };
+
+    assert_eq!(
+        Remapping::<Ch8, Ch10>::remap(Ch8::new(235), limited).into_inner(),
+        940,
+    );
+    assert_eq!(
+        Remapping::<Ch10, Ch8>::remap(Ch10::new(941), limited).into_inner(),
+        235,
+    );
+    assert_eq!(
+        Remapping::<Ch10, Ch16>::remap(Ch10::new(940), TO_FULL).into_inner(),
+        u16::MAX,
+    );
+    assert_eq!(
+        Remapping::<Ch16, Ch8>::remap(Ch16::new(0), TO_LIMITED).into_inner(),
+        16,
+    );
+}
+
+#[test]
+fn slice() {
+    let from = [16, 126, 235].map(Ch8::new);
+    let mut to = [Ch10::MIN; 3];
+
+    Remapping::<Ch8, Ch10>::remap_slice(&from, &mut to, TO_FULL);
+
+    assert_eq!(to.map(Ch10::into_inner), [0, 514, 1_023]);
+}
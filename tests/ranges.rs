@@ -1,3 +1,5 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
 #[test]
 fn ranges() {
     #![expect(dead_code)]
@@ -12,10 +14,12 @@ fn ranges() {
     #[derive(Debug)]
     struct Ranges {
         ch8_unsigned: MinMidMax<p_chan::unsigned::Ch8>,
+        ch10_unsigned: MinMidMax<p_chan::unsigned::Ch10>,
         ch12_unsigned: MinMidMax<p_chan::unsigned::Ch12>,
         ch16_unsigned: MinMidMax<p_chan::unsigned::Ch16>,
         ch24_unsigned: MinMidMax<p_chan::unsigned::Ch24>,
         ch8_signed: MinMidMax<p_chan::signed::Ch8>,
+        ch10_signed: MinMidMax<p_chan::signed::Ch10>,
         ch12_signed: MinMidMax<p_chan::signed::Ch12>,
         ch16_signed: MinMidMax<p_chan::signed::Ch16>,
         ch24_signed: MinMidMax<p_chan::signed::Ch24>,
@@ -27,6 +31,11 @@ fn ranges() {
             mid: p_chan::unsigned::Ch8::MID,
             max: p_chan::unsigned::Ch8::MAX,
         },
+        ch10_unsigned: MinMidMax {
+            min: p_chan::unsigned::Ch10::MIN,
+            mid: p_chan::unsigned::Ch10::MID,
+            max: p_chan::unsigned::Ch10::MAX,
+        },
         ch12_unsigned: MinMidMax {
             min: p_chan::unsigned::Ch12::MIN,
             mid: p_chan::unsigned::Ch12::MID,
@@ -48,6 +57,11 @@ fn ranges() {
             mid: p_chan::signed::Ch8::MID,
             max: p_chan::signed::Ch8::MAX,
         },
+        ch10_signed: MinMidMax {
+            min: p_chan::signed::Ch10::MIN,
+            mid: p_chan::signed::Ch10::MID,
+            max: p_chan::signed::Ch10::MAX,
+        },
         ch12_signed: MinMidMax {
             min: p_chan::signed::Ch12::MIN,
             mid: p_chan::signed::Ch12::MID,
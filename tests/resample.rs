@@ -0,0 +1,123 @@
+#![cfg(feature = "resample")]
+
+use core::f64::consts::TAU;
+
+use p_chan::{
+    resample::{Quality, Resampler},
+    signed::{Ch32, Ch64},
+};
+
+/// Resample a sine wave, returning the worst error (relative to the
+/// amplitude) against the ideal output, and the output RMS level.
+fn sine(quality: Quality, from: u32, to: u32, frequency: f64) -> (f64, f64) {
+    const LEN: usize = 4_800;
+
+    let mut resampler = Resampler::<Ch64>::new(quality, from, to);
+    let mut input = [Ch64::new(0.0); LEN];
+    let mut output = [Ch64::new(0.0); LEN * 2];
+
+    for (i, chan) in input.iter_mut().enumerate() {
+        *chan = Ch64::new((TAU * frequency * i as f64 / from as f64).sin());
+    }
+
+    assert!(resampler.max_output(LEN) <= output.len());
+
+    let written = resampler.process(&input, &mut output);
+    // Skip the start, which includes the initial silence
+    let skip = written / 4;
+    let mut error: f64 = 0.0;
+    let mut power = 0.0;
+
+    for (i, chan) in output[..written].iter().enumerate().skip(skip) {
+        let expected = (TAU * frequency * i as f64 / to as f64).sin();
+
+        error = error.max((chan.into_inner() - expected).abs());
+        power += chan.into_inner() * chan.into_inner();
+    }
+
+    (error, (power / (written - skip) as f64).sqrt())
+}
+
+#[test]
+fn passband() {
+    for frequency in [100.0, 1_000.0, 10_000.0] {
+        let (error, _) = sine(Quality::Sinc, 44_100, 48_000, frequency);
+
+        assert!(error < 1e-3, "{frequency} Hz: {error}");
+
+        let (error, _) = sine(Quality::Sinc, 48_000, 44_100, frequency);
+
+        assert!(error < 1e-3, "{frequency} Hz: {error}");
+    }
+
+    // Flat within 0.1 dB up to 16 kHz
+    let (_, rms) = sine(Quality::Sinc, 44_100, 48_000, 16_000.0);
+    let db = 20.0 * (rms * 2.0f64.sqrt()).log10();
+
+    assert!(db.abs() < 0.1, "{db} dB");
+}
+
+#[test]
+fn aliasing() {
+    // Above the output Nyquist frequency of 22.05 kHz
+    let (_, rms) = sine(Quality::Sinc, 48_000, 44_100, 23_000.0);
+    let db = 20.0 * (rms * 2.0f64.sqrt()).log10();
+
+    assert!(db < -60.0, "{db} dB");
+
+    // Without an anti-aliasing filter
+    let (_, rms) = sine(Quality::Linear, 48_000, 44_100, 23_000.0);
+    let db = 20.0 * (rms * 2.0f64.sqrt()).log10();
+
+    assert!(db > -30.0, "{db} dB");
+}
+
+#[test]
+fn interpolation() {
+    let (error, _) = sine(Quality::Linear, 44_100, 48_000, 100.0);
+
+    assert!(error < 1e-3, "{error}");
+
+    let (error, _) = sine(Quality::Cubic, 44_100, 48_000, 100.0);
+
+    assert!(error < 1e-5, "{error}");
+}
+
+#[test]
+fn identity() {
+    // (windowed-sinc always low-pass filters)
+    for quality in [Quality::Linear, Quality::Cubic] {
+        let mut resampler = Resampler::<Ch32>::new(quality, 48_000, 48_000);
+        let input: [Ch32; 64] =
+            core::array::from_fn(|i| Ch32::new((i as f32 * 0.1).sin()));
+        let mut output = [Ch32::new(0.0); 65];
+        let written = resampler.process(&input, &mut output);
+        let latency = resampler.latency();
+
+        assert_eq!(written, input.len() - latency);
+        assert_eq!(output[..written], input[..written]);
+    }
+}
+
+#[test]
+fn streaming() {
+    let input: [Ch32; 1_000] =
+        core::array::from_fn(|i| Ch32::new((i as f32 * 0.05).sin()));
+    let mut whole = Resampler::<Ch32>::new(Quality::Sinc, 44_100, 32_000);
+    let mut blocks = whole.clone();
+    let mut expected = [Ch32::new(0.0); 1_000];
+    let mut output = [Ch32::new(0.0); 1_000];
+    let len = whole.process(&input, &mut expected);
+    let mut written = 0;
+
+    for block in input.chunks(77) {
+        written += blocks.process(block, &mut output[written..]);
+    }
+
+    assert_eq!(written, len);
+    assert_eq!(output[..len], expected[..len]);
+
+    blocks.reset();
+    written = blocks.process(&input, &mut output);
+    assert_eq!(output[..written], expected[..len]);
+}
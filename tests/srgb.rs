@@ -0,0 +1,130 @@
+#![cfg(feature = "srgb")]
+
+use p_chan::{ops::Conversion, srgb, unsigned};
+
+#[test]
+fn decode_8() {
+    let decode = |code| {
+        Conversion::<srgb::Ch8, unsigned::Ch64>::convert(srgb::Ch8::new(code))
+            .into_inner()
+    };
+
+    assert_eq!(decode(0), 0.0);
+    assert_eq!(decode(255), 1.0);
+    assert!((decode(1) - 0.000_303_526_983_548_837_5).abs() < 1e-15);
+    assert!((decode(10) - 0.003_035_269_835_488_375).abs() < 1e-15);
+    assert!((decode(128) - 0.215_860_500_113_899_26).abs() < 1e-15);
+    assert!((decode(188) - 0.502_886_458_032_568_7).abs() < 1e-15);
+    assert_eq!(
+        Conversion::<srgb::Ch8, unsigned::Ch32>::convert(srgb::Ch8::new(128))
+            .into_inner(),
+        0.215_860_5,
+    );
+}
+
+#[test]
+fn encode_8() {
+    let encode = |linear| {
+        Conversion::<unsigned::Ch32, srgb::Ch8>::convert(unsigned::Ch32::new(
+            linear,
+        ))
+        .into_inner()
+    };
+
+    assert_eq!(encode(0.0), 0);
+    assert_eq!(encode(0.18), 118);
+    assert_eq!(encode(0.5), 188);
+    assert_eq!(encode(1.0), 255);
+    assert_eq!(encode(-1.0), 0);
+    assert_eq!(encode(2.0), 255);
+    assert_eq!(encode(f32::INFINITY), 255);
+    assert_eq!(encode(f32::NAN), 0);
+}
+
+#[test]
+fn round_trip_8() {
+    for code in 0..=u8::MAX {
+        let chan = srgb::Ch8::new(code);
+        let linear = Conversion::<srgb::Ch8, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, srgb::Ch8>::convert(linear),
+            chan
+        );
+
+        let linear = Conversion::<srgb::Ch8, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, srgb::Ch8>::convert(linear),
+            chan
+        );
+    }
+}
+
+#[test]
+fn round_trip_16() {
+    assert_eq!(
+        Conversion::<unsigned::Ch64, srgb::Ch16>::convert(unsigned::Ch64::new(
+            0.5
+        ))
+        .into_inner(),
+        48_192,
+    );
+    assert_eq!(
+        Conversion::<unsigned::Ch64, srgb::Ch16>::convert(unsigned::Ch64::new(
+            0.18
+        ))
+        .into_inner(),
+        30_235,
+    );
+
+    for code in 0..=u16::MAX {
+        let chan = srgb::Ch16::new(code);
+        let linear = Conversion::<srgb::Ch16, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, srgb::Ch16>::convert(linear),
+            chan,
+        );
+
+        let linear = Conversion::<srgb::Ch16, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, srgb::Ch16>::convert(linear),
+            chan,
+        );
+    }
+}
+
+#[test]
+fn midpoints_16() {
+    let encode = |linear| {
+        Conversion::<unsigned::Ch64, srgb::Ch16>::convert(unsigned::Ch64::new(
+            linear,
+        ))
+        .into_inner()
+    };
+
+    // Linear light just below and above halfway between each pair of codes
+    for code in 0..u16::MAX {
+        let encoded = (code as f64 + 0.5) / 65_535.0;
+        let linear = if encoded <= 0.040_45 {
+            encoded / 12.92
+        } else {
+            ((encoded + 0.055) / 1.055).powf(2.4)
+        };
+
+        assert_eq!(encode(linear * (1.0 - 1e-9)), code);
+        assert_eq!(encode(linear * (1.0 + 1e-9)), code + 1);
+    }
+}
+
+#[test]
+fn const_conversion() {
+    const LINEAR: unsigned::Ch32 =
+        Conversion::<srgb::Ch8, unsigned::Ch32>::convert(srgb::Ch8::MAX);
+    const ENCODED: srgb::Ch16 =
+        Conversion::<unsigned::Ch32, srgb::Ch16>::convert(LINEAR);
+
+    assert_eq!(ENCODED, srgb::Ch16::MAX);
+}
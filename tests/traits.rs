@@ -1,3 +1,5 @@
+#![cfg(all(feature = "signed", feature = "unsigned"))]
+
 use std::{
     fmt::{Debug, Display},
     ops::{Add, Sub},
@@ -37,16 +39,22 @@ fn impls_traits() {
 
     // unsigned
     assert_impl::<p_chan::unsigned::Ch8>();
+    assert_impl::<p_chan::unsigned::Ch10>();
+    assert_impl::<p_chan::unsigned::ChN<11>>();
     assert_impl::<p_chan::unsigned::Ch12>();
     assert_impl::<p_chan::unsigned::Ch16>();
     assert_impl::<p_chan::unsigned::Ch24>();
+    assert_impl::<p_chan::unsigned::Bf16>();
     assert_impl::<p_chan::unsigned::Ch32>();
     assert_impl::<p_chan::unsigned::Ch64>();
     // signed
     assert_impl::<p_chan::signed::Ch8>();
+    assert_impl::<p_chan::signed::Ch10>();
+    assert_impl::<p_chan::signed::ChN<11>>();
     assert_impl::<p_chan::signed::Ch12>();
     assert_impl::<p_chan::signed::Ch16>();
     assert_impl::<p_chan::signed::Ch24>();
+    assert_impl::<p_chan::signed::Bf16>();
     assert_impl::<p_chan::signed::Ch32>();
     assert_impl::<p_chan::signed::Ch64>();
 }
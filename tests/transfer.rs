@@ -0,0 +1,163 @@
+#![cfg(feature = "transfer")]
+
+use p_chan::{
+    ops::Conversion,
+    transfer::{Transfer, acescct, cineon, gamma22, gamma24, gamma26},
+    unsigned,
+};
+
+#[test]
+fn cineon() {
+    let decode = |code| {
+        Conversion::<cineon::Ch10, unsigned::Ch64>::convert(cineon::Ch10::new(
+            code,
+        ))
+        .into_inner()
+    };
+    let encode = |linear| {
+        Conversion::<unsigned::Ch32, cineon::Ch10>::convert(
+            unsigned::Ch32::new(linear),
+        )
+        .into_inner()
+    };
+
+    assert!(decode(95).abs() < 1e-12);
+    assert!((decode(445) - 0.149_303_712_021_677_6).abs() < 1e-12);
+    assert!((decode(685) - 1.0).abs() < 1e-12);
+    assert!((decode(1_023) - 13.521_694_568_553_714).abs() < 1e-12);
+    assert_eq!(
+        Conversion::<cineon::Ch10, unsigned::Ch32>::convert(cineon::Ch10::new(
+            685
+        ))
+        .into_inner(),
+        1.0,
+    );
+    assert_eq!(encode(0.0), 95);
+    assert_eq!(encode(0.18), 468);
+    assert_eq!(encode(1.0), 685);
+    assert_eq!(encode(100.0), 1_023);
+
+    for code in 95..=1_023 {
+        let chan = cineon::Ch10::new(code);
+        let linear = Conversion::<cineon::Ch10, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, cineon::Ch10>::convert(linear),
+            chan,
+        );
+    }
+}
+
+#[test]
+fn acescct() {
+    let encode = |linear| Transfer::AcesCct.encode(linear);
+    let decode = |encoded| Transfer::AcesCct.decode(encoded);
+
+    assert!((encode(0.0) - 0.072_905_534_195_835_5).abs() < 1e-12);
+    assert!((encode(0.18) - 0.413_588_402_492_442_3).abs() < 1e-12);
+    assert!((encode(1.0) - 9.72 / 17.52).abs() < 1e-12);
+    assert!((decode(0.5) - 0.514_056_913_328_032_9).abs() < 1e-12);
+    assert_eq!(decode(2.0), 65_504.0);
+
+    for linear in [-0.01, 0.0, 0.0078125, 0.18, 1.0, 100.0] {
+        assert!((decode(encode(linear)) - linear).abs() < 1e-9);
+    }
+
+    assert_eq!(
+        Conversion::<unsigned::Ch64, acescct::Ch12>::convert(
+            unsigned::Ch64::new(1.0),
+        ),
+        acescct::Ch12::new(2_272),
+    );
+    assert_eq!(
+        Conversion::<unsigned::Ch64, acescct::Ch16>::convert(
+            unsigned::Ch64::new(65_504.0),
+        ),
+        acescct::Ch16::MAX,
+    );
+
+    for code in (0..=u16::MAX).step_by(97) {
+        let chan = acescct::Ch16::new(code);
+        let linear = Conversion::<acescct::Ch16, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, acescct::Ch16>::convert(linear),
+            chan,
+        );
+    }
+}
+
+#[test]
+fn gamma() {
+    assert_eq!(
+        Conversion::<unsigned::Ch32, gamma22::Ch8>::convert(
+            unsigned::Ch32::new(0.18),
+        ),
+        gamma22::Ch8::new(117),
+    );
+    assert_eq!(
+        Conversion::<unsigned::Ch32, gamma22::Ch8>::convert(
+            unsigned::Ch32::new(-1.0),
+        ),
+        gamma22::Ch8::MIN,
+    );
+    assert_eq!(
+        Conversion::<gamma24::Ch16, unsigned::Ch32>::convert(
+            gamma24::Ch16::MAX,
+        ),
+        unsigned::Ch32::MAX,
+    );
+    let linear = Conversion::<gamma26::Ch10, unsigned::Ch64>::convert(
+        gamma26::Ch10::new(512),
+    );
+
+    assert!(
+        (linear.into_inner() - (512.0f64 / 1_023.0).powf(2.6)).abs() < 1e-15
+    );
+
+    for code in 0..=u8::MAX {
+        let chan = gamma22::Ch8::new(code);
+        let linear = Conversion::<gamma22::Ch8, unsigned::Ch64>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch64, gamma22::Ch8>::convert(linear),
+            chan,
+        );
+    }
+
+    for code in 0..=4_095 {
+        let chan = gamma24::Ch12::new(code);
+        let linear = Conversion::<gamma24::Ch12, unsigned::Ch32>::convert(chan);
+
+        assert_eq!(
+            Conversion::<unsigned::Ch32, gamma24::Ch12>::convert(linear),
+            chan,
+        );
+    }
+
+    // Custom gamma on floating-point values
+    let gamma = Transfer::Gamma(1.8);
+
+    assert!((gamma.decode(gamma.encode(0.18)) - 0.18).abs() < 1e-12);
+}
+
+#[test]
+fn builtin_curves() {
+    for transfer in [
+        Transfer::Linear,
+        Transfer::Srgb,
+        Transfer::Pq,
+        Transfer::Hlg,
+        Transfer::Gamma(2.4),
+        Transfer::Cineon,
+        Transfer::AcesCct,
+    ] {
+        for code in (0..=1_023).step_by(7) {
+            let encoded = code as f64 / 1_023.0;
+            let decoded = transfer.encode(transfer.decode(encoded));
+
+            // PQ doesn't quite encode zero to zero
+            assert!((decoded - encoded).abs() < 1e-6, "{transfer:?}");
+        }
+    }
+}
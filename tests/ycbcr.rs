@@ -0,0 +1,132 @@
+#![cfg(feature = "ycbcr")]
+
+use p_chan::{
+    ops::{Decoding, Encoding},
+    pixel::{Rgb, Ycbcr},
+    unsigned::{Ch8, Ch10, Ch12, Ch16, Ch32},
+    ycbcr::{Matrix, Range},
+};
+
+fn encode_8(rgb: [u8; 3], matrix: Matrix, range: Range) -> [u8; 3] {
+    let rgb = Rgb::from(rgb.map(Ch8::new));
+    let ycbcr = Encoding::<Rgb<Ch8>, Ycbcr<Ch8>>::encode(rgb, matrix, range);
+
+    <[Ch8; 3]>::from(ycbcr).map(Ch8::into_inner)
+}
+
+fn decode_8(ycbcr: [u8; 3], matrix: Matrix, range: Range) -> [u8; 3] {
+    let ycbcr = Ycbcr::from(ycbcr.map(Ch8::new));
+    let rgb = Decoding::<Ycbcr<Ch8>, Rgb<Ch8>>::decode(ycbcr, matrix, range);
+
+    <[Ch8; 3]>::from(rgb).map(Ch8::into_inner)
+}
+
+fn encode_10(rgb: [u16; 3], matrix: Matrix, range: Range) -> [u16; 3] {
+    let rgb = Rgb::from(rgb.map(Ch10::new));
+    let ycbcr = Encoding::<Rgb<Ch10>, Ycbcr<Ch10>>::encode(rgb, matrix, range);
+
+    <[Ch10; 3]>::from(ycbcr).map(Ch10::into_inner)
+}
+
+#[test]
+fn bt601() {
+    let (matrix, range) = (Matrix::Bt601, Range::Limited);
+
+    assert_eq!(encode_8([0, 0, 0], matrix, range), [16, 128, 128]);
+    assert_eq!(encode_8([255, 255, 255], matrix, range), [235, 128, 128]);
+    assert_eq!(encode_8([255, 0, 0], matrix, range), [81, 90, 240]);
+    assert_eq!(encode_8([0, 255, 0], matrix, range), [145, 54, 34]);
+    assert_eq!(encode_8([0, 0, 255], matrix, range), [41, 240, 110]);
+    // Quantization loses precision
+    assert_eq!(decode_8([81, 90, 240], matrix, range), [254, 0, 0]);
+    assert_eq!(decode_8([16, 128, 128], matrix, range), [0, 0, 0]);
+    // Super-black and super-white clamp
+    assert_eq!(decode_8([0, 128, 128], matrix, range), [0, 0, 0]);
+    assert_eq!(decode_8([255, 128, 128], matrix, range), [255, 255, 255]);
+
+    // JPEG (JFIF)
+    let range = Range::Full;
+
+    assert_eq!(encode_8([255, 0, 0], matrix, range), [76, 85, 255]);
+    assert_eq!(encode_8([255, 255, 255], matrix, range), [255, 128, 128]);
+    assert_eq!(decode_8([76, 85, 255], matrix, range), [254, 0, 0]);
+}
+
+#[test]
+fn bt709() {
+    let (matrix, range) = (Matrix::Bt709, Range::Limited);
+
+    assert_eq!(encode_8([255, 0, 0], matrix, range), [63, 102, 240]);
+    assert_eq!(encode_8([0, 255, 0], matrix, range), [173, 42, 26]);
+    assert_eq!(encode_8([0, 0, 255], matrix, range), [32, 240, 118]);
+    assert_eq!(decode_8([63, 102, 240], matrix, range), [255, 1, 0]);
+    assert_eq!(encode_10([1_023, 0, 0], matrix, range), [250, 409, 960]);
+    assert_eq!(encode_10([1_023; 3], matrix, range), [940, 512, 512]);
+    assert_eq!(encode_10([0; 3], Matrix::Bt709, Range::Full), [0, 512, 512]);
+}
+
+#[test]
+fn bt2020() {
+    let (matrix, range) = (Matrix::Bt2020, Range::Limited);
+
+    assert_eq!(encode_10([1_023, 0, 0], matrix, range), [294, 387, 960]);
+    assert_eq!(encode_10([0, 1_023, 0], matrix, range), [658, 189, 100]);
+    assert_eq!(encode_10([0, 0, 1_023], matrix, range), [116, 960, 476]);
+
+    let rgb = Rgb::new(Ch12::new(4_095), Ch12::new(2_000), Ch12::new(100));
+    let ycbcr = Encoding::<_, Ycbcr<Ch12>>::encode(rgb, matrix, range);
+
+    assert_eq!(
+        Decoding::<_, Rgb<Ch12>>::decode(ycbcr, matrix, range),
+        Rgb::new(rgb.r, rgb.g, Ch12::new(101)),
+    );
+}
+
+#[test]
+fn round_trip_16() {
+    for matrix in [Matrix::Bt601, Matrix::Bt709, Matrix::Bt2020] {
+        for range in [Range::Full, Range::Limited] {
+            let rgb = Rgb::new(Ch16::new(65_535), Ch16::new(30_000), Ch16::MIN);
+            let ycbcr = Encoding::<_, Ycbcr<Ch16>>::encode(rgb, matrix, range);
+
+            let decoded =
+                Decoding::<_, Rgb<Ch16>>::decode(ycbcr, matrix, range);
+            let channels = <[Ch16; 3]>::from(decoded).into_iter();
+
+            // Within one code value
+            for (a, b) in channels.zip(<[Ch16; 3]>::from(rgb)) {
+                assert!(a.into_inner().abs_diff(b.into_inner()) <= 1);
+            }
+        }
+    }
+}
+
+#[test]
+fn float() {
+    let rgb = Rgb::new(Ch32::new(1.0), Ch32::new(0.5), Ch32::new(0.25));
+    let full =
+        Encoding::<_, Ycbcr<Ch32>>::encode(rgb, Matrix::Bt709, Range::Full);
+    let black = Encoding::<_, Ycbcr<Ch32>>::encode(
+        Rgb::<Ch32>::BLACK,
+        Matrix::Bt709,
+        Range::Full,
+    );
+
+    assert_eq!(black, Ycbcr::new(Ch32::MIN, Ch32::MID, Ch32::MID));
+
+    let decoded =
+        Decoding::<_, Rgb<Ch32>>::decode(full, Matrix::Bt709, Range::Full);
+
+    for (a, b) in <[Ch32; 3]>::from(decoded).into_iter().zip([1.0, 0.5, 0.25]) {
+        assert!((a.into_inner() - b).abs() < 1e-6);
+    }
+
+    let limited = Encoding::<_, Ycbcr<Ch32>>::encode(
+        Rgb::<Ch32>::WHITE,
+        Matrix::Bt709,
+        Range::Limited,
+    );
+
+    assert!((limited.y.into_inner() - 235.0 / 255.0).abs() < 1e-6);
+    assert!((limited.cb.into_inner() - 128.0 / 255.0).abs() < 1e-6);
+}